@@ -6,6 +6,10 @@ cargo update -vw
 [[ -z "$(git status --porcelain)" ]]
 
 
+(cd src/core
+    cargo publish
+)
+
 (cd src/proc_macros
     cargo publish
 )
@@ -4,6 +4,9 @@
 #![forbid(unsafe_code)]
 #![allow(unused_braces)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 /// Main macro, entrypoint to the features of the crate.
 ///
 /// See the [main-level docs][`crate`] for more contextual information about this attribute.
@@ -50,7 +53,7 @@
 /// /// docs…
 /// #[named_generics_bundle(
 ///   $(
-///     // Optional. Path must be an absolute path (leading `crate`).
+///     // Optional. Path must be an absolute path (leading `crate` or `$crate`).
 ///     // It allows making the generated eponymous `SomeTrait!` macro be usable
 ///     // anywhere, that is, without having to have `SomeTrait` in the current scope.
 ///     //
@@ -62,13 +65,65 @@
 ///     // another crate (e.g. through some `#[macro_export]`ed macro).
 ///     path_to_named_generics_bundle_crate = ::my_crate::reexports::named_generics_bundle,
 ///   )?
+///   $(
+///     // Optional. Which stdlib traits `T : SomeTrait` is to entail.
+///     // Defaults to `Debug, Copy, Ord, Hash, Default, Send, Sync, Unpin`.
+///     implied_bounds($($StdTrait:ident),*),
+///   )?
+///   $(
+///     // Optional. Whether to `#[macro_export]` the eponymous `SomeTrait!` macro.
+///     // Defaults to `auto`, that is, iff `SomeTrait` is `pub`.
+///     export = auto | always | never,
+///   )?
+///   $(
+///     // Optional. Custom text for the error about some type not being a bundle.
+///     // `{Self}` refers to the offending type.
+///     on_unimplemented(
+///       $(message = "…",)?
+///       $(label = "…",)?
+///       $(note = "…",)*
+///     ),
+///   )?
+///   $(
+///     // Optional. Forbid implementations other than through the eponymous macro.
+///     sealed,
+///   )?
+///   $(
+///     // Optional. Report the expansion, as a compile-time warning.
+///     debug,
+///   )?
+///   $(
+///     // Optional. Generate a type-erased bundle, whose fields are `Box<dyn …>`s.
+///     dyn_companion = DynSomeTrait,
+///   )?
+///   $(
+///     // Optional. Generate a type-state builder, inferring the field types from values.
+///     builder = SomeTraitBuilder,
+///   )?
 /// )]
 /// $pub:vis
 /// trait SomeTrait $(: 'static)? {
 ///   $(
 ///     /// docs…
+///     $(
+///       // Optional. Extra keys the eponymous macro is to accept for this field.
+///       #[bundle(alias = $OldName:ident)]
+///     )*
+///     $(
+///       // Optional. Have the `builder` set this field through a type rather than a value.
+///       #[bundle(type_only)]
+///     )?
+///     $(
+///       // Optional. Allow omitting this field, which is then `Absent`.
+///       #[bundle(optional)]
+///     )?
 ///     type $EachAssocType:ident $(: $TraitBounds…)?;
 ///   )*
+///   $(
+///     // Optional. Provided items, moved to the generated `SomeTraitExt` trait.
+///     const $CONST:ident: $Ty = $value;
+///     fn $method(…) -> … { … }
+///   )*
 /// }
 /// # */
 /// ```
@@ -100,6 +155,154 @@
 ///     # ;
 ///     ```
 ///
+///     The macro docs feature a copy-pasteable invocation template, as well as a table of the
+///     fields (linking back to the associated types); and the trait docs link to the macro.
+///
+///   - ### Sealed bundles
+///
+///     With the `sealed` attribute arg, the trait can only be implemented through the
+///     eponymous macro, which leaves room to later change its encoding, or add fields to it,
+///     without SemVer hazards:
+///
+///     ```rust ,compile_fail
+///     mod upstream {
+///         #[::named_generics_bundle::named_generics_bundle(sealed)]
+///         pub trait DeviceSetup {
+///             type Fuel;
+///         }
+///     }
+///
+///     #[::named_generics_bundle::impl_bundle(upstream::DeviceSetup![Fuel = ()])]
+///     struct Mine; // ❌ `DeviceSetup` is sealed
+///     ```
+///
+///   - ### Presets
+///
+///     Canonical configurations can be named, and then extended at the use site with
+///     `..Preset` (fields not explicitly set are then picked from `Preset`):
+///
+///     ```rust
+///     # #[derive(Default)] pub struct Uranium; pub struct Mock; pub struct Turbo;
+///     #[::named_generics_bundle::named_generics_bundle]
+///     trait DeviceSetup {
+///         type Fuel;
+///         type Engine;
+///     }
+///
+///     // Shorthand for `pub type Production = DeviceSetup![Fuel = Uranium, Engine = Turbo];`
+///     DeviceSetup![pub preset Production = Fuel = Uranium, Engine = Turbo];
+///
+///     type Testing = DeviceSetup![..Production, Fuel = Mock];
+///
+///     fn fuel<P : DeviceSetup>() -> P::Fuel
+///     # where P::Fuel : Default
+///     # { <_>::default() }
+///     # /*
+///     {
+///         …
+///     }
+///     # */
+///
+///     let _: Uranium = fuel::<DeviceSetup![Engine = (), ..Production]>();
+///     ```
+///
+///   - ### Partial inference
+///
+///     In expression context (_e.g._, in a turbofish), fields may be left as `_` placeholders,
+///     to be inferred like any other generic parameter:
+///
+///     ```rust
+///     # pub struct Uranium; pub struct FluxCapacitor;
+///     #[::named_generics_bundle::named_generics_bundle]
+///     trait DeviceSetup {
+///         type Fuel;
+///         type Engine;
+///     }
+///
+///     fn run<P : DeviceSetup>(fuel: P::Fuel) {
+///         # let _ = fuel; /*
+///         …
+///         # */
+///     }
+///
+///     run::<DeviceSetup![Fuel = _, Engine = FluxCapacitor]>(Uranium);
+///     ```
+///
+///     This mixes with aliases and `..Preset` bases alike. A placeholder which cannot be
+///     inferred yields the usual "type annotations needed" error, and, as with any `_`, these
+///     are not allowed in item signatures (such as `type` aliases or presets).
+///
+///   - ### Optional fields
+///
+///     A `#[bundle(optional)]` field may be omitted from `Bundle![…]`, in which case it is
+///     [`Absent`], and a `BundleExt` trait (implemented for every bundle) is generated, with
+///     a `const HAS_FIELD: bool`, and an `Option`-returning accessor, per optional field, so
+///     that generic code may branch on it at compile time:
+///
+///     ```rust
+///     use ::named_generics_bundle::Absent;
+///
+///     pub trait Recorder { fn record(&self, event: &str); }
+///     # pub struct Uranium; pub struct Prometheus;
+///     # impl Recorder for Prometheus { fn record(&self, _: &str) {} }
+///
+///     // `Absent` ought to satisfy the bounds of the field.
+///     impl Recorder for Absent { fn record(&self, _: &str) {} }
+///
+///     #[::named_generics_bundle::named_generics_bundle]
+///     trait DeviceSetup {
+///         type Fuel;
+///         #[bundle(optional)]
+///         type Metrics : Recorder;
+///     }
+///
+///     fn boot<P : DeviceSetup>(metrics: &P::Metrics) {
+///         if P::HAS_METRICS {
+///             // …
+///         }
+///         if let Some(metrics) = P::metrics(metrics) {
+///             metrics.record("boot");
+///         }
+///     }
+///
+///     boot::<DeviceSetup![Fuel = Uranium]>(&Absent);
+///     boot::<DeviceSetup![Fuel = Uranium, Metrics = Prometheus]>(&Prometheus);
+///     ```
+///
+///     Presence is a matter of the key having been given (or inherited from a `..Preset`),
+///     whatever the type: an explicit `Metrics = Absent` counts as present (the `Absent`
+///     value then being handed to the accessor). To leave the field absent, omit its key.
+///
+///     From another crate, omitting the key of an optional field requires the trait to have a
+///     `path_to_this_very_module`: lacking it, the eponymous macro refers to `Absent` through the
+///     `path_to_named_generics_bundle_crate`, which need not resolve there.
+///
+///   - ### Provided items
+///
+///     Provided `fn`s and `const`s (that is, with a default body or value) are allowed in the
+///     trait definition, but since the eponymous macro does not implement them, they are moved
+///     to the `BundleExt` trait, implemented for every bundle (and which thus needs to be in
+///     scope):
+///
+///     ```rust
+///     #[::named_generics_bundle::named_generics_bundle]
+///     trait DeviceSetup {
+///         type Engine;
+///
+///         const MAX_BATCH: usize = 32;
+///
+///         fn name() -> &'static str where Self : Sized {
+///             ::core::any::type_name::<Self::Engine>()
+///         }
+///     }
+///
+///     fn boot<P : DeviceSetup>() {
+///         println!("Booting {} (by batches of {})", P::name(), P::MAX_BATCH);
+///     }
+///
+///     boot::<DeviceSetup![Engine = ()]>();
+///     ```
+///
 ///   - ### The `trait` definition remains `dyn`-compatible.
 ///
 ///     As a matter of fact, this is how the generated `Eponymous![]` macro works under the hood:
@@ -120,6 +323,12 @@
 ///     bumps, as **it will not be considered a breaking change**: the disclosure of this expansion
 ///     is done merely for educational/informative reasons.
 ///
+///     Hence the attribute rejecting, up-front, the fields which would make the trait
+///     `dyn`-incompatible: generic associated types, `where` clauses on fields (such as
+///     `type Fuel where Self : Sized;`), and `Self` used as a generic parameter of a field bound
+///     (`type Fuel : PartialEq<Self>;`), rather than through a projection (`Self::…`) or as an
+///     associated type binding (`Iterator<Item = Self>`).
+///
 ///   - ### Implied Bounds (`Sized + Copy + Clone + Send/Sync + …`)
 ///
 ///     `T : AnnotatedTrait` entails not only `: Sized`, but also every
@@ -144,12 +353,251 @@
 ///     # */
 ///     ```
 ///
+///   - ### Readable diagnostics
+///
+///     The trait is annotated with a `#[diagnostic::on_unimplemented]` listing its fields, so
+///     that using some non-bundle type where a bundle is expected:
+///
+///     ```rust ,compile_fail
+///     #[::named_generics_bundle::named_generics_bundle]
+///     trait DeviceSetup {
+///         type Fuel;
+///         type Engine;
+///     }
+///
+///     fn run<P : DeviceSetup>() {}
+///
+///     run::<String>(); // ❌
+///     ```
+///
+///     yields:
+///
+///     ```rust ,ignore
+///     # () /*
+///     error[E0277]: `String` is not a `DeviceSetup![…]` bundle
+///      --> src/main.rs:9:11
+///       |
+///     9 | run::<String>(); // ❌
+///       |       ^^^^^^ expected a `DeviceSetup![…]` bundle
+///       |
+///       = help: the trait `DeviceSetup` is not implemented for `String`
+///       = note: the `DeviceSetup` bundle expects the fields: `Fuel`, `Engine`
+///       = note: build one with `DeviceSetup![Fuel = …, Engine = …]`
+///     # */
+///     ```
+///
+///     This text can be customized through the `on_unimplemented(…)` attribute arg.
+///
+///     Moreover, providing a type which does not meet the bounds of its field:
+///
+///     ```rust ,compile_fail
+///     trait Burns {}
+///
+///     #[::named_generics_bundle::named_generics_bundle]
+///     trait DeviceSetup {
+///         type Fuel : Burns;
+///     }
+///
+///     fn run<P : DeviceSetup>() {}
+///
+///     run::<DeviceSetup![Fuel = String]>(); // ❌
+///     ```
+///
+///     yields an error naming the field:
+///
+///     ```rust ,ignore
+///     # () /*
+///     error[E0277]: `String` cannot be used as `Fuel` in `DeviceSetup![…]`: it does not implement `Burns`
+///       --> src/main.rs:10:7
+///        |
+///     10 | run::<DeviceSetup![Fuel = String]>(); // ❌
+///        |       ^^^^^^^^^^^^^^^^^^^^^^^^^^^ `Fuel = String` does not implement `Burns`
+///     # */
+///     ```
+///
+///       - Note: fields whose bounds mention `Self` other than through `Self::` projections
+///         fall back to the regular "trait bound not satisfied" error.
+///
+///   - ### Type-erased companion
+///
+///     With `dyn_companion = DynDeviceSetup`, a `DynDeviceSetup` bundle is also generated, whose
+///     fields are `Box<dyn Bound>`s, so that the same generic code may run fully dynamically,
+///     without monomorphizing every combination (this requires the `alloc` Cargo feature, which
+///     is enabled by default):
+///
+///     ```rust
+///     pub trait Burns { fn energy(&self) -> u32; }
+///
+///     // Required for `Box<dyn Burns> : Burns`.
+///     impl<F : ?Sized + Burns> Burns for Box<F> {
+///         fn energy(&self) -> u32 { (**self).energy() }
+///     }
+///     # pub struct Uranium; impl Burns for Uranium { fn energy(&self) -> u32 { 42 } }
+///
+///     #[::named_generics_bundle::named_generics_bundle(dyn_companion = DynDeviceSetup)]
+///     trait DeviceSetup {
+///         type Fuel : Burns + Send;
+///         type Payload; // `Box<dyn Any>`
+///     }
+///
+///     struct Device<S : DeviceSetup> {
+///         fuel: S::Fuel,
+///     }
+///
+///     let device = Device::<DynDeviceSetup> { fuel: Box::new(Uranium) };
+///     assert_eq!(device.fuel.energy(), 42);
+///     ```
+///
+///     Fields which cannot be erased (generic ones, or ones bounded by `dyn`-incompatible
+///     traits such as `Clone`, or by several non-auto traits) are reported at expansion time.
+///     A missing forwarding `impl … Bound for Box<…>` is reported on the field `Bound`, along
+///     with the `impl` to be added.
+///
+///   - ### Type-state builder
+///
+///     With `builder = DeviceSetupBuilder`, a `DeviceSetupBuilder` is also generated, with one
+///     (snake-cased) method per field, so that the field types get inferred from the values
+///     given to it. `.build()` is only available once every field has been set, and yields the
+///     bundle (as a zero-sized value), followed by the values, in order.
+///
+///     ```rust
+///     # pub struct Uranium; pub struct Turbo;
+///     #[::named_generics_bundle::named_generics_bundle(builder = DeviceSetupBuilder)]
+///     trait DeviceSetup {
+///         type Fuel;
+///         type Engine;
+///         // No value needed for this one: `.output::<String>()`.
+///         #[bundle(type_only)]
+///         type Output;
+///     }
+///
+///     let (bundle, fuel, engine) =
+///         DeviceSetupBuilder::new()
+///             .engine(Turbo)
+///             .output::<String>()
+///             .fuel(Uranium)
+///             .build()
+///     ;
+///     let _: DeviceSetup![Fuel = Uranium, Engine = Turbo, Output = String] = bundle;
+///     # let _ = (fuel, engine);
+///     ```
+///
+///     Calling `.build()` with some field left unset fails to compile, naming the missing
+///     field(s): ``the following trait bounds were not satisfied: `Unset: IsSet<Engine>` ``.
+///
+///   - ### Debugging the expansion
+///
+///     The `debug` attribute arg reports the expansion, that is, both the trait and its
+///     eponymous macro, as a (deprecation) warning pointing at `debug` (to be removed once done
+///     debugging). The expansion itself is unaffected, so the build goes on as it otherwise would.
+///     Enable the `debug` Cargo feature to have the expansion pretty-printed.
+///
+///     ```rust
+///     #[::named_generics_bundle::named_generics_bundle(debug)]
+///     trait DeviceSetup {
+///         type Fuel;
+///     }
+///     # type Example = DeviceSetup![Fuel = ()];
+///     ```
+///
+///   - ### Compile-time assertions
+///
+///     [`assert_same_bundle!`] and [`assert_bundle_field!`] check, at compile time, that two
+///     bundles are the same (or differ in exactly one field), or that some field of a bundle is
+///     a given type, reporting the mismatching field(s) by name:
+///
+///     ```rust
+///     # pub struct Uranium; pub struct Turbo;
+///     # #[::named_generics_bundle::named_generics_bundle]
+///     # trait DeviceSetup { type Fuel; type Engine; }
+///     # DeviceSetup![preset Production = Fuel = Uranium, Engine = Turbo];
+///     use ::named_generics_bundle::{assert_bundle_field, assert_same_bundle};
+///
+///     assert_same_bundle!(Production, DeviceSetup![Engine = Turbo, Fuel = Uranium] : DeviceSetup);
+///     # type Testing = DeviceSetup![..Production, Fuel = ()];
+///     assert_same_bundle!(Testing, Production : DeviceSetup, except Fuel);
+///     assert_bundle_field!(Production: DeviceSetup, Fuel == Uranium);
+///     ```
+///
+///   - ### Runtime selection
+///
+///     [`select_bundle!`] dispatches `&str` choices (e.g., from some config) to the matching
+///     instantiation, among presets or across the cartesian product of per-field alternatives,
+///     yielding an [`UnknownChoice`] error otherwise:
+///
+///     ```rust
+///     # pub struct Uranium; pub struct TestSubject; pub struct Turbo;
+///     # #[::named_generics_bundle::named_generics_bundle]
+///     # trait DeviceSetup { type Fuel; type Engine; }
+///     # fn run<P : DeviceSetup>() {}
+///     # let config = "t";
+///     ::named_generics_bundle::select_bundle!(DeviceSetup {
+///         Fuel in { "u" => Uranium, "t" => TestSubject } = config,
+///         Engine in { "turbo" => Turbo } = "turbo",
+///     } => |P| run::<P>())?;
+///     # Ok::<_, ::named_generics_bundle::UnknownChoice<'_>>(())
+///     ```
+///
+///   - ### Test matrices
+///
+///     [`bundle_test_matrix!`] generates one `#[test]` per combination of field candidates,
+///     named after the chosen types (e.g., `check__Fuel_Uranium__Engine_Turbo`), and checks
+///     that every field is covered.
+///
+/// </details>
+///
+/// # Evolving a bundle
+///
+/// <details open class="custom"><summary><span class="summary-box"><span>Click to hide</span></span></summary>
+///
+///   - A `#[deprecated]` field makes every `Bundle![…]` invocation setting it emit a deprecation
+///     warning.
+///
+///   - A renamed field can keep accepting its older name(s) through `#[bundle(alias = …)]`:
+///
+///     ```rust
+///     #[::named_generics_bundle::named_generics_bundle]
+///     trait DeviceSetup {
+///         #[bundle(alias = Gas)]
+///         type Fuel;
+///
+///         #[deprecated(note = "no longer used")]
+///         type Motor;
+///     }
+///
+///     # #[allow(deprecated)]
+///     type Example = DeviceSetup![
+///         Gas = (), // same as `Fuel = ()`.
+///         Motor = (), // ⚠️ warning: use of deprecated associated type
+///     ];
+///     ```
+///
+///   - Fields can be `#[cfg]`-gated; the `Bundle![…]` macro then silently discards the keys
+///     (and aliases) of cfg-ed out fields, so that invocations need not be `#[cfg]`-gated
+///     themselves:
+///
+///     ```rust
+///     # trait Recorder {}
+///     #[::named_generics_bundle::named_generics_bundle]
+///     trait DeviceSetup {
+///         type Fuel;
+///
+///         #[cfg(feature = "metrics")]
+///         type Metrics : Recorder;
+///     }
+///
+///     type Example = DeviceSetup![
+///         Fuel = (),
+///         Metrics = (), // Ignored unless `feature = "metrics"` is enabled.
+///     ];
+///     ```
+///
 /// </details>
 ///
 /// # Quirks
 ///
 ///   - To keep things simple, the attribute rejects trait with generics parameters, or associated
-///     items other than types.
+///     items other than types and provided `fn`s and `const`s (see [above](#provided-items)).
 ///
 ///   - ## The `path_to_this_very_module = ` attribute arg
 ///
@@ -224,6 +672,14 @@
 ///         part of this specifier with `$crate::` so as to make the `Example![]` macro it
 ///         generates, resilient to being used across crates / from a downstream dependent crate.
 ///
+///       - `$crate::some_module` is accepted as well, for bundles emitted by a `macro_rules!`
+///         (which has no other way to name its own crate), as long as that macro is invoked
+///         within its own crate, at that very module.
+///
+///       - It is also what lets the `better-docs` feature render the trait without its hidden
+///         generic param and `ImpliedPredicate` supertrait: lacking a `path_to_this_very_module`,
+///         the trait is rendered as is.
+///
 ///   - ## The `path_to_named_generics_bundle_crate = ` attribute arg
 ///
 ///     Since this macro stems from a `proc-macro = true` backend using a frontend/façade package,
@@ -265,22 +721,223 @@
 ///     # fn main() {}
 ///     ```
 ///
+///     A path which does not refer to `::named_generics_bundle` (or to a re-export of it)
+///     results in a "`…::nmb` does not refer to `named_generics_bundle`" error, spanned on it
+///     (along with an "unresolved import `…::nmb::ඞ`" one, but for no other).
+///
 pub use ::named_generics_bundle_proc_macros::named_generics_bundle;
 
+/// Define a (nameable) marker type implementing some bundle trait.
+///
+/// Hand-written impls of a bundle trait need the implementor to meet its implied bounds
+/// (`Debug + Copy + Ord + Hash + Default + …`); this attribute takes care of it by `#[derive()]`ing
+/// every stdlib derivable trait on the (unit) `struct`, and then implementing the bundle trait
+/// with the given fields:
+///
+/// ```rust
+/// # pub struct Uranium; pub struct Turbo;
+/// #[::named_generics_bundle::named_generics_bundle]
+/// trait DeviceSetup {
+///     type Fuel;
+///     type Engine;
+/// }
+///
+/// #[::named_generics_bundle::impl_bundle(DeviceSetup![
+///     Fuel = Uranium,
+///     Engine = Turbo,
+/// ])]
+/// pub struct Prod;
+///
+/// fn run<P : DeviceSetup>() {}
+///
+/// run::<Prod>();
+/// ```
+///
+/// Field names are checked against the trait by the resulting `impl` (note that field aliases
+/// are not supported here, though), and omitted optional fields are [`Absent`].
+///
+/// ```rust ,compile_fail
+/// #[::named_generics_bundle::named_generics_bundle]
+/// trait DeviceSetup {
+///     type Fuel;
+/// }
+///
+/// #[::named_generics_bundle::impl_bundle(DeviceSetup![Fule = ()])] // ❌
+/// struct Prod;
+/// ```
+pub use ::named_generics_bundle_proc_macros::impl_bundle;
+
+/// Function-like flavor of [`#[named_generics_bundle]`][`named_generics_bundle`], able to
+/// define several bundles at once.
+///
+/// This is mostly intended for `macro_rules!` authors wishing to emit bundles in bulk.
+///
+/// Args shared by every trait in the block go in a leading `#![named_generics_bundle(…)]`
+/// inner attribute, whereas per-trait args go in a `#[named_generics_bundle(…)]` attribute on
+/// the trait itself (taking precedence over the shared ones).
+///
+/// ```rust
+/// # pub extern crate named_generics_bundle;
+/// #
+/// macro_rules! bundles {( $($Name:ident),* $(,)? ) => (
+///     ::named_generics_bundle::named_generics_bundles! {
+///         #![named_generics_bundle(
+///             implied_bounds(Debug, Clone, Copy),
+///         )]
+///       $(
+///         trait $Name {
+///             type Item;
+///         }
+///       )*
+///     }
+/// )}
+///
+/// bundles![A, B];
+///
+/// ::named_generics_bundle::named_generics_bundles! {
+///     #[named_generics_bundle(implied_bounds(Default))]
+///     trait C {}
+/// }
+///
+/// fn demo<P : A, Q : B, R : C>() {}
+/// #
+/// # fn main() {
+/// #   demo::<A![Item = ()], B![Item = u8], C![]>();
+/// # }
+/// ```
+pub use ::named_generics_bundle_proc_macros::named_generics_bundles;
+
+/// Define, once, a crate-local alias of [`named_generics_bundles!`] with crate-wide defaults for its
+/// attribute args.
+///
+/// Every arg but `path_to_this_very_module` can be given a default, and an explicit arg on a given
+/// trait still takes precedence over the default one.
+///
+/// ```rust
+/// # pub extern crate named_generics_bundle;
+/// #
+/// pub mod __internals {
+///     pub use ::named_generics_bundle as nmb;
+/// }
+///
+/// ::named_generics_bundle::configure! {
+///     macro_rules! my_named_generics_bundle {
+///         path_to_named_generics_bundle_crate = $crate::__internals::nmb,
+///         implied_bounds(Debug, Clone, Copy, Send, Sync),
+///         export = never,
+///     }
+/// }
+///
+/// my_named_generics_bundle! {
+///     pub trait Bundle {
+///         type Foo;
+///     }
+/// }
+///
+/// // Several traits can be defined at once, and per-trait args are to be given through an
+/// // (inert) `#[named_generics_bundle(…)]` attribute.
+/// my_named_generics_bundle! {
+///     #[named_generics_bundle(implied_bounds(Debug, Copy, Default))]
+///     trait OtherBundle {
+///         type Bar;
+///     }
+///
+///     trait YetAnotherBundle {}
+/// }
+///
+/// fn demo<B : Bundle>() -> impl Clone + Copy + Send {
+///     ::core::marker::PhantomData::<B>
+/// }
+/// #
+/// # fn main() {
+/// #   _ = demo::<Bundle![Foo = ()]>();
+/// #   _ = <OtherBundle![Bar = ()]>::default();
+/// # }
+/// ```
+///
+/// Within the `configure!` invocation, `$crate` and `crate` both refer to the crate
+/// defining the alias.
+///
+/// The alias is also usable as an attribute, through [`#[apply]`][`macro@apply`].
+pub use ::named_generics_bundle_proc_macros::configure;
+
+/// The attribute form of a [`configure!`]d alias: `#[apply(alias!)] trait …` expands to
+/// `alias! { trait … }`.
+///
+/// A crate re-exporting this library may thus re-export `apply` along with its
+/// (`#[macro_export]`ed) alias, for its users to define bundles through an attribute without
+/// depending on `::named_generics_bundle` themselves:
+///
+/// ```rust
+/// # pub extern crate named_generics_bundle;
+/// #
+/// pub mod __internals {
+///     pub use ::named_generics_bundle as nmb;
+/// }
+///
+/// pub use ::named_generics_bundle::apply;
+///
+/// ::named_generics_bundle::configure! {
+///     macro_rules! bundle {
+///         path_to_named_generics_bundle_crate = $crate::__internals::nmb,
+///         implied_bounds(Debug, Clone, Copy),
+///     }
+/// }
+///
+/// #[apply(bundle!)]
+/// pub trait Bundle {
+///     type Foo;
+/// }
+///
+/// // Per-trait args are to be given through an (inert) `#[named_generics_bundle(…)]` attribute.
+/// #[apply(bundle!)]
+/// #[named_generics_bundle(implied_bounds(Debug, Copy, Default))]
+/// pub trait OtherBundle {
+///     type Bar;
+/// }
+/// #
+/// # fn main() {
+/// #   _ = <OtherBundle![Bar = ()]>::default();
+/// # }
+/// ```
+pub use ::named_generics_bundle_proc_macros::apply;
+
+pub use optional::Absent;
+
+pub use select_bundle::UnknownChoice;
+
+#[doc(hidden)] /** Not part of the public API */ #[macro_export]
+macro_rules! ඞexpand {( $($input:tt)* ) => (
+    $($input)*
+)}
+
 // macro internals
 #[doc(hidden)] /** Not part of the public API */ pub
 mod ඞ {
     pub use ::core::{
         self,
-        cmp::Ord,
+        clone::Clone,
+        cmp::{Eq, Ord, PartialEq, PartialOrd},
         default::Default,
         fmt::Debug,
         hash::Hash,
         marker::{Copy, Send, Sync, Unpin},
     };
+    #[cfg(feature = "alloc")]
+    pub use ::alloc::boxed::Box;
     pub use ::implied_bounds::ImpliedPredicate;
+    pub use ::named_generics_bundle_proc_macros::{
+        ඞbundle_builder as bundle_builder,
+        ඞbundle_keys as bundle_keys,
+        ඞbundle_test_matrix as bundle_test_matrix,
+        ඞimpl_bundle_with_fields as impl_bundle_with_fields,
+    };
     pub use crate::{
+        assertions::{DifferentField, SameBundle, SameField, SameType},
+        builder::{IsSet, OrAbsent, Set, SetType, Unset},
         const_helpers::*,
+        optional::{Absent, NotPresent, Present, Presence},
+        select_bundle::{last_path_segment, str_choice},
     };
 
     /// This type is used to convey the notion that users of this attribute are
@@ -292,12 +949,29 @@ mod ඞ {
     /// of "breaking changes" should I decide to change what the `Eponymous![]` macro unsugars to.
     pub type ඞ<T> = <T as Identity>::ItSelf;
 
+    /// Marker looked for by the expansion, to validate `path_to_named_generics_bundle_crate`.
+    pub fn is_named_generics_bundle<T>() {}
+
+    /// The (passthrough) expansion of a bundle with a `path_to_named_generics_bundle_crate`,
+    /// so that a wrong path does not get to emit any items (see `validate_crate_path.rs`).
+    pub use crate::ඞexpand as expand;
+
     pub trait Identity { type ItSelf : ?Sized; }
     impl<T : ?Sized> Identity for T { type ItSelf = Self; }
 }
 
+mod assertions;
+
+mod builder;
+
 mod const_helpers;
 
+mod optional;
+
+mod select_bundle;
+
+mod test_matrix;
+
 #[doc = include_str!("compile_fail_tests.md")]
 mod _compile_fail_tests {}
 
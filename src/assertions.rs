@@ -0,0 +1,205 @@
+//! Compile-time assertions about bundles, mostly meant for tests.
+
+/// Compile-time assertion that two bundles are the same.
+///
+/// ```rust
+/// # pub struct Uranium; pub struct Turbo; pub struct Mock;
+/// use ::named_generics_bundle::{assert_same_bundle, named_generics_bundle};
+///
+/// #[named_generics_bundle]
+/// trait DeviceSetup {
+///     type Fuel;
+///     type Engine;
+/// }
+///
+/// DeviceSetup![preset Production = Fuel = Uranium, Engine = Turbo];
+/// type Testing = DeviceSetup![..Production, Fuel = Mock];
+///
+/// // Type equality.
+/// assert_same_bundle!(Testing, DeviceSetup![Engine = Turbo, Fuel = Mock]);
+/// // Field-by-field equality, naming the mismatching field(s), if any.
+/// assert_same_bundle!(Testing, DeviceSetup![Engine = Turbo, Fuel = Mock] : DeviceSetup);
+/// // Differing in exactly that one field.
+/// assert_same_bundle!(Testing, Production : DeviceSetup, except Fuel);
+/// ```
+///
+/// ```rust ,compile_fail
+/// # pub struct Uranium; pub struct Turbo; pub struct Mock;
+/// # use ::named_generics_bundle::{assert_same_bundle, named_generics_bundle};
+/// # #[named_generics_bundle]
+/// # trait DeviceSetup {
+/// #     type Fuel;
+/// #     type Engine;
+/// # }
+/// # DeviceSetup![preset Production = Fuel = Uranium, Engine = Turbo];
+/// type Testing = DeviceSetup![..Production, Fuel = Mock];
+///
+/// // error[E0277]: mismatched `Fuel` field: expected `Uranium`, found `Mock`
+/// assert_same_bundle!(Testing, Production : DeviceSetup);
+/// ```
+///
+///   - The type-equality form is stricter: a bundle defined through
+///     [`#[impl_bundle]`][`crate::impl_bundle`] is never the same type as a `DeviceSetup![…]`
+///     one, even with the same fields.
+///
+///   - The field-by-field forms expect a path to the bundle trait (and its eponymous macro).
+///
+///   - With `except Field`, that field being the same in both bundles is reported as a (somewhat
+///     cryptic) "type annotations needed" error, about `DifferentField<Field, …>`.
+#[macro_export]
+macro_rules! assert_same_bundle {
+    (
+        $P:ty, $Q:ty $(,)?
+    ) => (
+        const _: () = {
+            type ඞP = $P;
+            type ඞQ = $Q;
+            fn ඞcheck<ඞP : ?Sized + $crate::ඞ::SameBundle<ඞQ>, ඞQ : ?Sized>() {}
+            let _ = ඞcheck::<ඞP, ඞQ>;
+        };
+    );
+
+    (
+        $P:ty, $Q:ty : $($Bundle:ident)::+ $(,)?
+    ) => (
+        $($Bundle)::+! {
+            @ඞwith_fields [$crate::ඞassert_same_fields] [$P] [$Q]
+        }
+    );
+
+    (
+        $P:ty, $Q:ty : $($Bundle:ident)::+, except $Field:ident $(,)?
+    ) => (
+        // Every other field is the same…
+        $crate::assert_same_bundle!(
+            $P,
+            $($Bundle)::+![..$Q, $Field = <$P as $($Bundle)::+>::$Field] : $($Bundle)::+
+        );
+        // …but for that one.
+        const _: () = {
+            type ඞP = <$P as $($Bundle)::+>::$Field;
+            type ඞQ = <$Q as $($Bundle)::+>::$Field;
+            fn ඞcheck<
+                ඞP : ?Sized + $crate::ඞ::DifferentField<ඞField, ඞQ, ඞAmbiguity>,
+                ඞQ : ?Sized,
+                ඞField,
+                ඞAmbiguity,
+            >() {}
+            #[allow(nonstandard_style)]
+            fn ඞnamed<$Field>() {
+                let _ = ඞcheck::<ඞP, ඞQ, $Field, _>;
+            }
+            let _ = ඞnamed::<()>;
+        };
+    );
+}
+
+#[doc(hidden)] /** Not part of the public API */ #[macro_export]
+macro_rules! ඞassert_same_fields {(
+    [$P:ty] [$Q:ty] [$Bundle:path] [$( $(#[$cfg:meta])* $Field:ident )*] $optional:tt
+) => (
+    const _: () = {
+        // Through aliases, so that the errors point at the assertion, rather than at the
+        // expansion of some `Bundle![…]` type.
+        type ඞP = $P;
+        type ඞQ = $Q;
+        $(
+            $(#[$cfg])*
+            const _: () = {
+                fn ඞcheck<ඞP : ?Sized + $Bundle, ඞQ : ?Sized + $Bundle, ඞField>()
+                where
+                    <ඞP as $Bundle>::$Field : $crate::ඞ::SameField<
+                        ඞField,
+                        <ඞQ as $Bundle>::$Field,
+                    >,
+                {}
+                // Generic over a type parameter named after the field, for the diagnostic.
+                #[allow(nonstandard_style)]
+                fn ඞnamed<$Field>() {
+                    let _ = ඞcheck::<ඞP, ඞQ, $Field>;
+                }
+                let _ = ඞnamed::<()>;
+            };
+        )*
+    };
+)}
+
+/// Compile-time assertion about the fields of a bundle.
+///
+/// ```rust
+/// # pub struct Uranium; pub struct Turbo;
+/// use ::named_generics_bundle::{assert_bundle_field, named_generics_bundle};
+///
+/// #[named_generics_bundle]
+/// trait DeviceSetup {
+///     type Fuel;
+///     type Engine;
+/// }
+///
+/// DeviceSetup![preset Production = Fuel = Uranium, Engine = Turbo];
+///
+/// assert_bundle_field!(Production: DeviceSetup, Fuel == Uranium);
+/// assert_bundle_field!(Production: DeviceSetup, Fuel == Uranium, Engine == Turbo);
+/// ```
+///
+/// ```rust ,compile_fail
+/// # pub struct Uranium; pub struct Turbo;
+/// # use ::named_generics_bundle::{assert_bundle_field, named_generics_bundle};
+/// # #[named_generics_bundle]
+/// # trait DeviceSetup {
+/// #     type Fuel;
+/// #     type Engine;
+/// # }
+/// # DeviceSetup![preset Production = Fuel = Uranium, Engine = Turbo];
+/// // error[E0277]: mismatched `Engine` field: expected `Uranium`, found `Turbo`
+/// assert_bundle_field!(Production: DeviceSetup, Engine == Uranium);
+/// ```
+#[macro_export]
+macro_rules! assert_bundle_field {(
+    $P:ty : $Bundle:path, $( $Field:ident == $T:ty ),+ $(,)?
+) => (
+    const _: () = {
+        type ඞP = $P;
+        $(
+            const _: () = {
+                type ඞT = $T;
+                fn ඞcheck<ඞP : ?Sized + $Bundle, ඞT : ?Sized, ඞField>()
+                where
+                    <ඞP as $Bundle>::$Field : $crate::ඞ::SameField<ඞField, ඞT>,
+                {}
+                #[allow(nonstandard_style)]
+                fn ඞnamed<$Field>() {
+                    let _ = ඞcheck::<ඞP, ඞT, $Field>;
+                }
+                let _ = ඞnamed::<()>;
+            };
+        )+
+    };
+)}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` and `{Q}` are not the same bundle",
+    label = "not the same bundle as `{Q}`",
+    note = "`assert_same_bundle!(P, Q : Bundle)` names the mismatching field(s), if any",
+)]
+pub trait SameBundle<Q : ?Sized> {}
+
+impl<P : ?Sized> SameBundle<P> for P {}
+
+/// `Field` is a type parameter named after the field, for the diagnostic.
+#[diagnostic::on_unimplemented(
+    message = "mismatched `{Field}` field: expected `{Expected}`, found `{Self}`",
+    label = "`{Field}` is `{Self}`",
+)]
+pub trait SameField<Field, Expected : ?Sized> {}
+
+impl<Field, T : ?Sized> SameField<Field, T> for T {}
+
+/// Implemented twice (hence ambiguously) when `Self` and `Other` are the same type.
+pub trait DifferentField<Field, Other : ?Sized, Ambiguity> {}
+
+impl<Field, T : ?Sized, U : ?Sized> DifferentField<Field, U, ()> for T {}
+
+pub enum SameType {}
+
+impl<Field, T : ?Sized> DifferentField<Field, T, SameType> for T {}
@@ -0,0 +1,89 @@
+//! The type-states of the `builder = …` generated builders.
+
+use ::core::marker::PhantomData;
+
+use crate::optional::{Absent, NotPresent, Present};
+
+/// A field which has not been set yet.
+pub struct Unset;
+
+/// A field set through a value.
+pub struct Set<T>(pub T);
+
+/// A field set through a type.
+pub struct SetType<T : ?Sized>(PhantomData<fn() -> T>);
+
+impl<T : ?Sized> SetType<T> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T : ?Sized> Default for SetType<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `Field` is a type named after the field, for the diagnostics (mostly `Unset: IsSet<Field>`).
+#[diagnostic::on_unimplemented(
+    message = "missing `{Field}` field",
+    label = "`{Field}` has not been set",
+    note = "every field is to be set (through its builder method) before calling `.build()`",
+)]
+pub trait IsSet<Field> {
+    type Type : ?Sized;
+    type Value;
+    fn value(self) -> Self::Value;
+}
+
+impl<Field, T> IsSet<Field> for Set<T> {
+    type Type = T;
+    type Value = T;
+    fn value(self) -> T {
+        self.0
+    }
+}
+
+impl<Field, T : ?Sized> IsSet<Field> for SetType<T> {
+    type Type = T;
+    type Value = PhantomData<T>;
+    fn value(self) -> PhantomData<T> {
+        PhantomData
+    }
+}
+
+/// The state of an optional field, which may be left unset (and thus [`Absent`]).
+pub trait OrAbsent {
+    type Type : ?Sized;
+    type Presence;
+    type Value;
+    fn value(self) -> Self::Value;
+}
+
+impl OrAbsent for Unset {
+    type Type = Absent;
+    type Presence = NotPresent;
+    type Value = Absent;
+    fn value(self) -> Absent {
+        Absent
+    }
+}
+
+impl<T> OrAbsent for Set<T> {
+    type Type = T;
+    type Presence = Present;
+    type Value = T;
+    fn value(self) -> T {
+        self.0
+    }
+}
+
+impl<T : ?Sized> OrAbsent for SetType<T> {
+    type Type = T;
+    type Presence = Present;
+    type Value = PhantomData<T>;
+    fn value(self) -> PhantomData<T> {
+        PhantomData
+    }
+}
@@ -79,7 +79,7 @@ fn constcat<const N: usize, const NUM_INPUTS: usize>(
         }
     }
 
-    if let Err(_) = ::core::str::from_utf8(&ret) {
+    if ::core::str::from_utf8(&ret).is_err() {
         panic!("unreachable: non-UTF8 concat output");
     }
 
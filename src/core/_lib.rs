@@ -0,0 +1,833 @@
+//! The expansion logic of the [`::named_generics_bundle`] proc-macros, on `proc-macro2` types.
+//!
+//! Not intended for direct use, but for snapshot-testing expansions, or for other proc-macros
+//! to generate bundles programmatically. Otherwise, use
+//! <https://docs.rs/named-generics-bundle> instead.
+//!
+//! Beware that the precise shape of the expansions is not part of the SemVer guarantees.
+//!
+//! [`::named_generics_bundle`]: https://docs.rs/named-generics-bundle
+#![allow(nonstandard_style, unused_imports, unused_braces)]
+#![allow(clippy::empty_docs, clippy::redundant_pattern)]
+
+use ::core::{
+    mem,
+    ops::Not as _,
+};
+use ::proc_macro2::{*,
+    Span,
+    TokenStream as TokenStream2,
+    TokenTree as TT,
+};
+use ::quote::{
+    format_ident,
+    quote,
+    quote_spanned,
+    ToTokens,
+};
+use ::syn::{*,
+    parse::{Parse, Parser, ParseStream},
+    punctuated::Punctuated,
+    Result, // Explicitly shadow it
+    spanned::Spanned,
+};
+
+mod args;
+
+mod builder;
+
+mod cfg_chain;
+
+mod bundles;
+
+mod configure;
+
+mod debug;
+
+mod dyn_companion;
+
+mod dyn_compatibility;
+
+mod eponymous_macro;
+
+mod ext_trait;
+
+mod field_args;
+
+mod field_checks;
+
+mod impl_bundle;
+
+mod test_matrix;
+
+mod validate_crate_path;
+
+mod validate_module_path;
+
+/// The expansion of `#[named_generics_bundle(#args)] #input`.
+///
+/// The resulting errors are meant to be emitted with `Error::into_compile_error()`, or prefixed
+/// with the name of the calling macro beforehand.
+pub
+fn named_generics_bundle(
+    args: TokenStream2,
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    named_generics_bundle_impl(args, input)
+}
+
+/// Same as [`named_generics_bundle()`], but for an already-parsed `trait` definition, as may be
+/// convenient for other proc-macros to generate bundles programmatically.
+pub
+fn named_generics_bundle_from_trait(
+    args: TokenStream2,
+    item: &ItemTrait,
+) -> Result<TokenStream2>
+{
+    named_generics_bundle_impl(args, item.to_token_stream())
+}
+
+/// The expansion of `named_generics_bundles! { #input }`.
+pub
+fn named_generics_bundles(
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    bundles::named_generics_bundles_impl(input)
+}
+
+/// The expansion of `#[impl_bundle(#args)] #input`.
+pub
+fn impl_bundle(
+    args: TokenStream2,
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    impl_bundle::impl_bundle_impl(args, input)
+}
+
+/// The expansion of the hidden `impl_bundle_with_fields!` callback, that is, of
+/// `[#krate] [#TraitPath] [#Marker] [#bindings] [#TraitPath] [#fields] [#optional_fields]`, as
+/// emitted by the eponymous macro of the bundle on behalf of [`impl_bundle()`].
+pub
+fn impl_bundle_with_fields(
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    impl_bundle::impl_bundle_with_fields_impl(input)
+}
+
+/// The expansion of the hidden `bundle_keys!` callback, that is, of
+/// `[#callback] [#prefix] #named_generics`, as emitted by the eponymous macro of the bundle:
+/// `#callback! { #prefix #named_generics }`, but for every `Key = Type` becoming
+/// `Key [Key = Type]`.
+pub
+fn bundle_keys(
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    eponymous_macro::bundle_keys_impl(input)
+}
+
+/// The expansion of `configure! { #input }`.
+pub
+fn configure(
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    configure::configure_impl(input)
+}
+
+/// The expansion of `#[apply(#args)] #input`.
+pub
+fn apply(
+    args: TokenStream2,
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    configure::apply_impl(args, input)
+}
+
+/// The expansion of the `bundle_test_matrix!` callback, that is, of
+/// `[#matrix] [#TraitPath] [#( #(#cfg_attrs)* #FieldName )*] [#optional_fields]`, as emitted by
+/// the eponymous macro of the bundle.
+pub
+fn bundle_test_matrix(
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    test_matrix::bundle_test_matrix_impl(input)
+}
+
+/// The expansion of the hidden `bundle_builder!` callback, that is, of
+/// `[#krate] [#pub_] [#TraitName] [#BuilderName] [#( [#FieldName #kind $(optional)?] )*]`
+/// (`#kind` being `value` or `type_only`), as emitted alongside a trait with the
+/// `builder = …` attribute arg.
+pub
+fn bundle_builder(
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    builder::bundle_builder_impl(input)
+}
+
+/// Somewhat human-friendly stringification of some tokens, for diagnostics and docs.
+fn pretty_tokens(tokens: impl ToTokens) -> String {
+    [(" :: ", "::"), (":: ", "::"), (" <", "<"), ("< ", "<"), (" >", ">"), (" ,", ","), (" (", "(")]
+        .iter()
+        .fold(tokens.to_token_stream().to_string(), |s, (from, to)| s.replace(from, to))
+}
+
+/// `FluxCapacitor` to `flux_capacitor`, and `HTTPClient` to `http_client`.
+fn snake_case(FieldName @ _: &Ident) -> Ident {
+    let name = FieldName.to_string();
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut ret = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|it| it.is_lowercase());
+            if prev.is_lowercase()
+            || prev.is_ascii_digit()
+            || (prev.is_uppercase() && next_is_lower)
+            {
+                ret.push('_');
+            }
+        }
+        ret.extend(c.to_lowercase());
+    }
+    match parse_str::<Ident>(&ret) {
+        Ok(_) => Ident::new(&ret, FieldName.span()),
+        // A keyword.
+        Err(_) => Ident::new_raw(&ret, FieldName.span()),
+    }
+}
+
+/// `#krate`, as seen from a (hidden) submodule of the module of the trait, which is to glob-import
+/// the items of the latter (for any other relative path, such as one starting with a local
+/// `mod`ule, to resolve), while an extern crate keeps on being reached through the extern prelude.
+fn nested_krate(krate: &TokenStream2) -> TokenStream2 {
+    let mut tts = krate.clone().into_iter();
+    match tts.next() {
+        Some(TT::Ident(ident)) if ident == "self" => {
+            let super_ = Ident::new("super", ident.span());
+            quote!(#super_ #(#tts)*)
+        },
+        Some(TT::Ident(ident)) if ident == "super" => quote!(super::#krate),
+        _ => krate.clone(),
+    }
+}
+
+/// The hidden associated type flagging whether the optional `FieldName` is present.
+fn presence_flag(FieldName @ _: &Ident) -> Ident {
+    format_ident!("ඞ{FieldName}ඞpresent", span = FieldName.span())
+}
+
+/// Whether `Self` is used other than as the prefix of a projection (`Self::…`, or
+/// `<Self as …>::…`).
+fn mentions_non_projection_self(ts: TokenStream2) -> bool {
+    let mut tts = ts.into_iter().peekable();
+    while let Some(tt) = tts.next() {
+        match tt {
+            TT::Ident(ident) if ident == "Self" => match tts.peek() {
+                Some(TT::Punct(p)) if p.as_char() == ':' => {},
+                Some(TT::Ident(as_)) if as_ == "as" => {},
+                _ => return true,
+            },
+            TT::Group(g) if mentions_non_projection_self(g.stream()) => return true,
+            _ => {},
+        }
+    }
+    false
+}
+
+/// Like `ItemTrait`, but restricted.
+struct RestrictedItemTrait {
+    attrs: Vec<Attribute>,
+    pub_: Visibility,
+    trait_: Token![trait],
+    TraitName: Ident,
+    supertraits: Punctuated<TypeParamBound, Token![+]>,
+    braces: token::Brace,
+    body: Punctuated<TraitItemType, parse::Nothing>,
+    /// Provided `fn`s and `const`s, to be moved to the extension trait.
+    provided: Vec<TraitItem>,
+}
+
+impl Parse for RestrictedItemTrait {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let braces;
+        let mut provided = vec![];
+        let mut attrs: Vec<Attribute> = Attribute::parse_outer(input)?;
+        Ok(Self {
+            pub_: input.parse()?,
+            trait_: input.parse()?,
+            TraitName: input.parse()?,
+            supertraits: {
+                let semi: Option<Token![:]> = input.parse()?;
+                let mut ret = Punctuated::default();
+                if semi.is_some() {
+                    while input.peek(token::Brace).not() {
+                        ret.push_value(input.parse()?);
+                        if let Some(plus) = input.parse()? {
+                            ret.push_punct(plus);
+                        } else {
+                            break;
+                        }
+                    }
+                }
+                ret
+            },
+            body: {
+                let inner;
+                braces = braced!(inner in input);
+                let input = &inner;
+
+                attrs.extend(Attribute::parse_inner(input)?.into_iter().map(|mut attr| {
+                    attr.style = AttrStyle::Outer;
+                    attr
+                }));
+
+                let mut body = Punctuated::new();
+                while input.is_empty().not() {
+                    match input.parse()? {
+                        TraitItem::Type(ty) => {
+                            body.push_value(ty);
+                            body.push_punct(parse::Nothing);
+                        },
+                        item @ (TraitItem::Fn(_) | TraitItem::Const(_)) => provided.push(item),
+                        item => return Err(Error::new_spanned(
+                            item,
+                            "expected `type` (or a provided `fn` or `const`)",
+                        )),
+                    }
+                }
+                body
+            },
+            braces,
+            attrs,
+            provided,
+        })
+    }
+}
+
+fn named_generics_bundle_impl(
+    args: TokenStream2,
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    // By default deny any attribute present.
+    let mut args: args::Args = parse2(args)?;
+    let RestrictedItemTrait {
+        mut attrs,
+        pub_,
+        trait_,
+        ref TraitName,
+        mut supertraits,
+        braces,
+        mut body,
+        provided,
+    } = parse2(input)?;
+    // Moved to the `…Ext` trait, so they need a default.
+    for item in &provided {
+        let (spanned, what): (&dyn ToTokens, _) = match item {
+            TraitItem::Fn(TraitItemFn { default: None, sig, .. }) => (sig, "body (provided `fn`s"),
+            TraitItem::Const(TraitItemConst { default: None, ident, .. }) => {
+                (ident, "value (provided `const`s")
+            },
+            _ => continue,
+        };
+        return Err(Error::new_spanned(spanned, format_args!(
+            "expected a default {what} are moved to the `{TraitName}Ext` trait)",
+        )));
+    }
+    // Extra (inert) `#[named_generics_bundle(…)]` attributes, such as the ones from a
+    // `configure!`d alias invocation.
+    for attr in mem::take(&mut attrs) {
+        if attr.path().segments.last().is_some_and(|it| it.ident == "named_generics_bundle") {
+            args.extend(match attr.meta {
+                Meta::Path(_) => parse2(quote!())?,
+                _ => attr.parse_args()?,
+            })?;
+        } else {
+            attrs.push(attr);
+        }
+    }
+    args.apply_defaults();
+    // The `macro_rules!` bodies refer to the crate through `#macro_krate`; the other items
+    // through `#krate`.
+    let macro_krate = &args.krate.as_ref().map_or_else(|| quote_spanned!(Span::mixed_site()=>
+        ::named_generics_bundle
+    ), ToTokens::to_token_stream);
+    let (krate, validate_crate_path) =
+        &validate_crate_path::validate(TraitName, macro_krate, &args.krate)
+    ;
+
+    if supertraits.empty_or_trailing().not() {
+        supertraits.push_punct(<_>::default());
+    }
+
+    let field_args = field_args::extract(&mut body)?;
+    let dyn_compatibility_errors = dyn_compatibility::check(TraitName, &body, &field_args)?;
+
+    // The hidden presence flags of the optional fields.
+    let (OptionalFlag, OptionalCfgAttrs): (Vec<_>, Vec<_>) =
+        body.iter()
+            .zip(&field_args)
+            .filter(|(_, args)| args.optional.is_some())
+            .map(|(ty, args)| (presence_flag(&ty.ident), &args.cfg_attrs))
+            .unzip()
+    ;
+
+    let braced_body = &mut quote::quote!();
+    braces.surround(braced_body, |ts| {
+        body.to_tokens(ts);
+        ts.extend(quote!(#(
+            #(#OptionalCfgAttrs)*
+            #[doc(hidden)]
+            type #OptionalFlag : #krate::ඞ::Presence;
+        )*));
+    });
+
+    let EachTypeName @ _ = body.iter().map(|ty| &ty.ident).chain(&OptionalFlag);
+    let EachCfgAttr @ _ = field_args.iter().map(|args| &args.cfg_attrs).chain(OptionalCfgAttrs);
+
+    let ඞTraitName @ _ = &format_ident!(
+        "__proper_macro_rules_scopingඞnamed_generics_bundleඞ{TraitName}",
+    );
+    // Under `better-docs`, the trait is rendered without its `ඞImpliedDeriveBounds` param nor
+    // `ImpliedPredicate` supertrait (its implied bounds becoming plain supertraits); the `dyn` type
+    // of the bundles is then that of a hidden `ඞTraitඞdyn::Trait` companion, which needs a
+    // `path_to_this_very_module` to be named from wherever the eponymous macro is invoked
+    // (lacking it, the trait is rendered as is).
+    let doc_dyn_module = &(cfg!(feature = "better-docs") && args.module_path.is_some())
+        .then(|| format_ident!("ඞ{TraitName}ඞdyn"))
+    ;
+
+    let macro_doc_intro = &format!(" Instantiate a [`{TraitName}`](trait@{TraitName}) bundle.");
+    let fields_table = &if body.is_empty() {
+        vec![" This bundle has no fields.".into()]
+    } else {
+        let mut lines = vec![
+            " | Field | Bounds | Docs |".to_owned(),
+            " |-------|--------|------|".to_owned(),
+        ];
+        lines.extend(body.iter().zip(&field_args).map(|(ty, args)| {
+            let bounds = match ty.bounds.is_empty() {
+                true => String::new(),
+                false => format!("`{}`", pretty_tokens(&ty.bounds)),
+            };
+            let docs = ty.attrs.iter().filter_map(|attr| match &attr.meta {
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    value: Expr::Lit(ExprLit { lit: Lit::Str(doc), .. }),
+                    ..
+                }) if path.is_ident("doc") => Some(doc.value().trim().to_owned()),
+                _ => None,
+            }).collect::<Vec<_>>().join(" ").replace('|', "\\|");
+            let optional = match args.optional {
+                Some(_) => " (optional)",
+                None => "",
+            };
+            format!(
+                " | [`{FieldName}`]({TraitName}::{FieldName}){optional} | {bounds} | {docs} |",
+                FieldName = ty.ident,
+            ).replace('\n', " ")
+        }));
+        lines
+    };
+
+    // Copy-pasteable; this also nudges `rust-analyzer` auto-complete into suggesting square
+    // brackets for these macros.
+    let TraitName_macro_invocation_template = &{
+        let mut lines = vec![" ```rust ,ignore".to_owned()];
+        if body.is_empty() {
+            lines.push(format!(" {TraitName}![]"));
+        } else {
+            lines.push(format!(" {TraitName}!["));
+            lines.extend(body.iter().zip(&field_args).map(|(ty, args)| {
+                let line = match ty.bounds.is_empty() {
+                    true => format!("     {} = /* … */,", ty.ident),
+                    false => format!(
+                        "     {} = /* impl {} */,",
+                        ty.ident,
+                        pretty_tokens(&ty.bounds),
+                    ),
+                };
+                match args.optional {
+                    Some(_) => format!("{line} // optional"),
+                    None => line,
+                }
+            }));
+            lines.push(" ]".to_owned());
+        }
+        lines.push(" ```".to_owned());
+        lines
+    };
+
+
+    let is_pub = matches!(pub_, Visibility::Public { .. });
+    let macro_export = match args.export() {
+        args::Export::Auto => is_pub,
+        args::Export::Always => true,
+        args::Export::Never => false,
+    };
+    let if_macro_export = macro_export.then_some(quote!());
+    let if_macro_export = if_macro_export.as_slice();
+    // A non-`#[macro_export]`ed macro cannot be re-exported beyond the current crate.
+    let macro_pub = &if is_pub && macro_export.not() {
+        quote_spanned!(pub_.span()=> pub(crate))
+    } else {
+        pub_.to_token_stream()
+    };
+
+    let ImpliedBound @ _ = &args.implied_bounds();
+    let implied_bounds_doc = &format!(
+        " The resulting type also implements `{}`, so as to be",
+        ImpliedBound.iter().map(ToString::to_string).collect::<Vec<_>>().join(" + "),
+    );
+
+    let fields = &body.iter().map(|ty| format!("`{}`", ty.ident)).collect::<Vec<_>>().join(", ");
+    let on_unimplemented = args.on_unimplemented.as_ref();
+    let message = on_unimplemented.and_then(|it| it.message.clone()).unwrap_or_else(|| {
+        LitStr::new(&format!("`{{Self}}` is not a `{TraitName}![…]` bundle"), TraitName.span())
+    });
+    let label = on_unimplemented.and_then(|it| it.label.clone()).unwrap_or_else(|| {
+        LitStr::new(&format!("expected a `{TraitName}![…]` bundle"), TraitName.span())
+    });
+    let notes = match on_unimplemented.map(|it| &it.notes[..]) {
+        Some(notes @ [_, ..]) => notes.to_vec(),
+        _ => vec![
+            LitStr::new(
+                &if body.is_empty() {
+                    format!("the `{TraitName}` bundle has no fields")
+                } else {
+                    format!("the `{TraitName}` bundle expects the fields: {fields}")
+                },
+                TraitName.span(),
+            ),
+            LitStr::new(
+                &format!(
+                    "build one with `{TraitName}![{}]`",
+                    body.iter().map(|ty| format!("{} = …", ty.ident)).collect::<Vec<_>>().join(", "),
+                ),
+                TraitName.span(),
+            ),
+        ],
+    };
+
+    let field_checks::FieldChecks {
+        defs: field_checks_defs,
+        module_defs: field_checks_module_defs,
+        where_clauses: field_checks_where_clauses,
+        fields_module,
+        type_checked,
+    } = field_checks::field_checks(
+        &pub_,
+        TraitName,
+        args.module_path.is_some(),
+        &body,
+        &field_args,
+    );
+
+    let validate_module_path = validate_module_path::validate(krate, &args.module_path);
+
+    let builder = args.builder.as_ref().map(|BuilderName| builder::builder(
+        krate,
+        &pub_,
+        TraitName,
+        BuilderName,
+        &body,
+        &field_args,
+        &type_checked,
+    ));
+
+    let ext_trait = ext_trait::ext_trait(krate, &pub_, TraitName, &body, &field_args, &provided);
+
+    let dyn_companion = match &args.dyn_companion {
+        Some(DynTraitName) => Some(dyn_companion::dyn_companion(
+            krate,
+            &pub_,
+            TraitName,
+            DynTraitName,
+            &body,
+            &field_args,
+        )?),
+        None => None,
+    };
+
+    // Sealed: a (nameless) supertrait only implemented by the eponymous macro encoding.
+    let ඞsealed @ _ = &format_ident!("__ඞ{TraitName}ඞsealed");
+    let sealed_message = format!(
+        "`{TraitName}` is sealed: it cannot be implemented other than through `{TraitName}![…]`",
+    );
+    let sealed_label = format!("`{{Self}}` cannot implement `{TraitName}`");
+    let DynTraitName @ _ = args.dyn_companion.iter();
+    let nested_krate = &nested_krate(krate);
+    let (sealed_supertrait, sealed_defs) = match &args.sealed {
+        None => (quote!(), quote!()),
+        Some(_) => (
+            quote_spanned!(Span::mixed_site()=>
+                #ඞsealed::Sealed +
+            ),
+            quote_spanned!(Span::mixed_site()=>
+                #[doc(hidden)]
+                mod #ඞsealed {
+                    #[allow(unused_imports)]
+                    use super::*;
+
+                    #[diagnostic::on_unimplemented(
+                        message = #sealed_message,
+                        label = #sealed_label,
+                    )]
+                    pub trait Sealed {}
+
+                    impl<ඞDyn : ?::core::marker::Sized>
+                        Sealed
+                    for
+                        #nested_krate::ඞ::core::marker::PhantomData<
+                            fn(#nested_krate::ඞ::ඞ<()>) -> ඞDyn
+                        >
+                    {}
+
+                    #(
+                        impl Sealed for super::#DynTraitName {}
+                    )*
+                }
+            ),
+        ),
+    };
+
+    // How the eponymous macro is to refer back to itself (much like it does with `dyn Trait`).
+    // Note: `$crate::#ඞTraitName` is not an option, since macro-expanded `#[macro_export]`ed
+    // macros cannot be referred to by absolute paths from within the current crate.
+    let macro_self_path = if let Some(module_path) = &args.module_path {
+        let module_path = configure::lift_crate_to_dollar_crate(module_path.to_token_stream());
+        quote!(#module_path::#TraitName)
+    } else {
+        quote!(#TraitName)
+    };
+
+    if let Some(p) = &mut args.module_path {
+        let last_span = p.segments.last().unwrap().span();
+        p.segments.push_punct(token::PathSep {
+            spans: [last_span; 2],
+        });
+    }
+    let mb_module_path =
+        args.module_path
+            .as_ref()
+            .map(|p| p.to_token_stream().into_iter().collect::<Vec<_>>())
+            .map(|mut tts| match tts.first().unwrap() {
+                // Lift `crate` to `$crate` to be used in the `macro_rules!` def below.
+                TT::Ident(krate) if krate == "crate" => {
+                    let mut dollar = Punct::new('$', Spacing::Joint);
+                    dollar.set_span(krate.span());
+                    tts.insert(0, dollar.into());
+                    tts
+                },
+                // Already resolved (to the crate of the `macro_rules!` which emitted it).
+                TT::Ident(dollar_crate) if dollar_crate == "$crate" => tts,
+                _ => unreachable!("as per the current `Parse` implementation"),
+            })
+            .unwrap_or_default()
+    ;
+    // let QualifiedTraitName @ _ = args.module_path.as_ref().map_or_else(
+    //     || TraitName.to_token_stream(),
+    //     |p| {
+    //         let span = p.segments.last().unwrap().span();
+    //         let TraitName @ _ = Ident::new(&TraitName.to_string(), span);
+    //         quote_spanned!(span=>
+    //             #p #TraitName
+    //         )
+    //     },
+    // );
+    //
+    // Note: we do not use this trick to validate anymore, since we have `validate_module_path`.
+    let QualifiedTraitName = TraitName;
+
+    let EachTypeName2 @ _ = EachTypeName.clone();
+    let EachCfgAttr2 @ _ = EachCfgAttr.clone();
+    let blanket_impl = cfg_chain::assemble(
+        &format!("{TraitName}ඞimpl"),
+        field_checks_where_clauses,
+        |field_checks_where_clauses| quote_spanned!(Span::mixed_site()=>
+            // while we could just use `#TraitName` here, this gives us a simple sanity check
+            // that the provided `module_path` (if any), be correct.
+            impl<ඞDyn : ?::core::marker::Sized>
+                #TraitName
+            for
+                #krate::ඞ::core::marker::PhantomData<fn(#krate::ඞ::ඞ<()>) -> ඞDyn>
+            where
+                // Checked first, for nicer diagnostics.
+                #field_checks_where_clauses
+                ඞDyn : #QualifiedTraitName<()>,
+            {
+                #(
+                    #(#EachCfgAttr)*
+                    type #EachTypeName = ඞDyn::#EachTypeName;
+                )*
+            }
+        ),
+    );
+
+    let implied_predicate = |ImpliedSubject @ _: TokenStream2| {
+        // `Rhs = Self` would otherwise refer to the (`dyn`-incompatible) `Self` of the trait.
+        let ImpliedBoundGenerics @ _ = ImpliedBound.iter().map(|bound| {
+            matches!(&*bound.to_string(), "PartialEq" | "PartialOrd")
+                .then(|| quote!(<#ImpliedSubject>))
+        });
+        quote_spanned!(Span::mixed_site()=>
+            #krate::ඞ::ImpliedPredicate<
+                #ImpliedSubject,
+                Impls : #(#krate::ඞ::#ImpliedBound #ImpliedBoundGenerics +)*,
+            >
+        )
+    };
+
+    let eponymous_macro = eponymous_macro::EponymousMacro {
+        krate: macro_krate,
+        local_krate: krate,
+        TraitName,
+        ඞTraitName,
+        macro_self_path,
+        mb_module_path: &mb_module_path,
+        fields_module: &fields_module,
+        type_checked: &type_checked,
+        body: &body,
+        field_args: &field_args,
+        doc_dyn_module: doc_dyn_module.as_ref(),
+        macro_export,
+        macro_attrs: quote!(
+            #[doc = #macro_doc_intro]
+            ///
+            #(#[doc = #TraitName_macro_invocation_template])*
+            ///
+            #(#[doc = #fields_table])*
+            ///
+            #[doc = #implied_bounds_doc]
+            /// dumb-stdlib-`#[derive()]`-friendly.
+            #(#if_macro_export
+                #[macro_export]
+            )*
+            #[doc(hidden)]
+        ),
+    }.definition();
+
+    // Make the eponymous macro discoverable from the trait docs (unless it is less visible).
+    let trait_macro_doclink = (is_pub.not() || macro_export).then(|| format!(
+        " Instantiated through the [`{TraitName}![…]`](macro@{TraitName}) macro.",
+    ));
+    let trait_macro_doclink = trait_macro_doclink.iter();
+    let trait_attrs = &quote_spanned!(Span::mixed_site()=>
+        #(#attrs)*
+        #(
+            ///
+            #[doc = #trait_macro_doclink]
+        )*
+        #[diagnostic::on_unimplemented(
+            message = #message,
+            label = #label,
+            #(note = #notes,)*
+        )]
+    );
+    let (if_not_doc, doc_trait) = if let Some(doc_dyn_module) = doc_dyn_module {
+        // Plain supertraits, rather than the `ImpliedPredicate` indirection.
+        let ImpliedSupertraits @ _ = ImpliedBound.iter().map(|bound| {
+            let generics = matches!(&*bound.to_string(), "PartialEq" | "PartialOrd")
+                .then(|| quote!(<Self>));
+            quote!(#krate::ඞ::#bound #generics +)
+        });
+        (
+            quote!(#[cfg(not(doc))]),
+            quote_spanned!(Span::mixed_site()=>
+                #[cfg(doc)]
+                #trait_attrs
+                #pub_
+                #trait_ #TraitName
+                :
+                    #supertraits
+                    #sealed_supertrait
+                    #(#ImpliedSupertraits)*
+                #braced_body
+
+                #[cfg(doc)]
+                #[doc(hidden)]
+                #[allow(nonstandard_style)]
+                #pub_ mod #doc_dyn_module {
+                    use super::*;
+
+                    pub #trait_ #TraitName
+                    :
+                        #supertraits
+                    #braced_body
+                }
+
+                #[cfg(doc)]
+                const _: () = {
+                    impl<ඞDyn : ?::core::marker::Sized>
+                        #TraitName
+                    for
+                        #krate::ඞ::core::marker::PhantomData<fn(#krate::ඞ::ඞ<()>) -> ඞDyn>
+                    where
+                        ඞDyn : #doc_dyn_module::#TraitName,
+                    {
+                        #(
+                            #(#EachCfgAttr2)*
+                            type #EachTypeName2 = ඞDyn::#EachTypeName2;
+                        )*
+                    }
+                };
+            ),
+        )
+    } else {
+        (quote!(), quote!())
+    };
+    let ImpliedPredicate @ _ = implied_predicate(quote!(ඞImpliedDeriveBounds));
+
+    let ret = quote_spanned!(Span::mixed_site()=>
+        #validate_module_path
+
+        #doc_trait
+
+        #if_not_doc
+        #trait_attrs
+        #pub_
+        #trait_ #TraitName <ඞImpliedDeriveBounds = Self>
+        :
+            #supertraits
+            #sealed_supertrait
+            #ImpliedPredicate +
+        #braced_body
+
+        #sealed_defs
+
+        #field_checks_module_defs
+
+        #ext_trait
+
+        #dyn_companion
+
+        #dyn_compatibility_errors
+
+        #builder
+
+        #if_not_doc
+        #[allow(deprecated)]
+        const _: () = {
+            #field_checks_defs
+
+            #blanket_impl
+        };
+
+        #eponymous_macro
+        #[doc(inline)]
+        #macro_pub use #ඞTraitName as #TraitName;
+    );
+    let ret = validate_crate_path::wrap(krate, validate_crate_path, ret);
+    Ok(match &args.debug {
+        Some(debug) => debug::dump(debug, TraitName, ret),
+        None => ret,
+    })
+}
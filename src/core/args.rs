@@ -0,0 +1,363 @@
+use super::*;
+
+pub(crate) struct Args {
+    pub(crate) module_path: Option<Path>,
+    pub(crate) krate: Option<Path>,
+    pub(crate) implied_bounds: Option<Punctuated<Ident, Token![,]>>,
+    pub(crate) export: Option<Ident>,
+    pub(crate) on_unimplemented: Option<OnUnimplemented>,
+    pub(crate) sealed: Option<kw::sealed>,
+    pub(crate) debug: Option<kw::debug>,
+    /// The name of the type-erased companion bundle to generate, if any.
+    pub(crate) dyn_companion: Option<Ident>,
+    /// The name of the type-state builder to generate, if any.
+    pub(crate) builder: Option<Ident>,
+    /// Defaults, as provided by a `named_generics_bundles!` block, or a `configure!`d alias.
+    defaults: Option<Box<Args>>,
+}
+
+/// The stdlib traits which `PhantomData<…>` implements (and which can be `#[derive()]`d).
+pub(crate) const IMPLIABLE_BOUNDS: &[&str] = &[
+    "Debug",
+    "Clone", "Copy",
+    "PartialEq", "Eq", "PartialOrd", "Ord",
+    "Hash",
+    "Default",
+    "Send", "Sync", "Unpin",
+];
+
+pub(crate) const DEFAULT_IMPLIED_BOUNDS: &[&str] = &[
+    "Debug",
+    "Copy",
+    "Ord",
+    "Hash",
+    "Default",
+    "Send", "Sync", "Unpin",
+];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Export {
+    /// `#[macro_export]` the eponymous macro iff the trait is `pub`.
+    Auto,
+    Always,
+    Never,
+}
+
+/// Custom text for the `#[diagnostic::on_unimplemented]` of the trait.
+pub(crate) struct OnUnimplemented {
+    kw: kw::on_unimplemented,
+    pub(crate) message: Option<LitStr>,
+    pub(crate) label: Option<LitStr>,
+    pub(crate) notes: Vec<LitStr>,
+}
+
+impl ToTokens for OnUnimplemented {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        self.kw.to_tokens(tokens)
+    }
+}
+
+impl Parse for OnUnimplemented {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let kw = input.parse()?;
+        let contents;
+        parenthesized!(contents in input);
+        let input = &contents;
+        let mut message = None;
+        let mut label = None;
+        let mut notes = vec![];
+        while input.is_empty().not() {
+            let snoopy = input.lookahead1();
+            let slot = match () {
+                _case if snoopy.peek(kw::message) => {
+                    let _: kw::message = input.parse().unwrap();
+                    &mut message
+                },
+                _case if snoopy.peek(kw::label) => {
+                    let _: kw::label = input.parse().unwrap();
+                    &mut label
+                },
+                _case if snoopy.peek(kw::note) => {
+                    let _: kw::note = input.parse().unwrap();
+                    let _: Token![=] = input.parse()?;
+                    notes.push(input.parse()?);
+                    let _: Option<Token![,]> = input.parse()?;
+                    continue;
+                },
+                _default => return Err(snoopy.error()),
+            };
+            if slot.is_some() {
+                return Err(input.error("duplicate entry"));
+            }
+            let _: Token![=] = input.parse()?;
+            *slot = Some(input.parse()?);
+            let _: Option<Token![,]> = input.parse()?;
+        }
+        Ok(Self { kw, message, label, notes })
+    }
+}
+
+pub(crate) mod kw {
+    ::syn::custom_keyword!(path_to_this_very_module);
+    ::syn::custom_keyword!(path_to_named_generics_bundle_crate);
+    ::syn::custom_keyword!(implied_bounds);
+    ::syn::custom_keyword!(export);
+    ::syn::custom_keyword!(on_unimplemented);
+    ::syn::custom_keyword!(sealed);
+    ::syn::custom_keyword!(debug);
+    ::syn::custom_keyword!(dyn_companion);
+    ::syn::custom_keyword!(builder);
+    ::syn::custom_keyword!(message);
+    ::syn::custom_keyword!(label);
+    ::syn::custom_keyword!(note);
+    ::syn::custom_keyword!(ඞdefaults);
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        const USAGE: &str = "help:
+Usage:\
+    #[named_generics_bundle(
+        // Optional. Must be an absolute path (leading `crate` or `$crate`).
+        path_to_this_very_module = crate::some::path,
+        // Optional.
+        path_to_named_generics_bundle_crate = some::path,
+        // Optional. Defaults to `Debug, Copy, Ord, Hash, Default, Send, Sync, Unpin`.
+        implied_bounds(Debug, Clone, …),
+        // Optional. Whether to `#[macro_export]` the eponymous macro.
+        export = auto | always | never,
+        // Optional. Custom text for when some type is not a bundle (`{Self}` is available).
+        on_unimplemented(message = \"…\", label = \"…\", note = \"…\"),
+        // Optional. Forbid implementations other than through the eponymous macro.
+        sealed,
+        // Optional. Report the expansion, as a compile-time warning.
+        debug,
+        // Optional. Generate a type-erased bundle, with `Box<dyn …>` fields.
+        dyn_companion = DynSomeTrait,
+        // Optional. Generate a type-state builder, inferring the field types from values.
+        builder = SomeTraitBuilder,
+    )]\
+        ";
+        || -> Result<_> {
+            let mut module_path = None;
+            let mut krate = None;
+            let mut implied_bounds = None;
+            let mut export = None;
+            let mut on_unimplemented = None;
+            let mut sealed = None;
+            let mut debug = None;
+            let mut dyn_companion = None;
+            let mut builder = None;
+            let mut defaults = None;
+
+            while input.is_empty().not() {
+                let snoopy = input.lookahead1();
+                match () {
+                    _case if snoopy.peek(kw::path_to_this_very_module) => {
+                        if module_path.is_some() {
+                            return Err(input.error("duplicate entry"));
+                        }
+                        let _: kw::path_to_this_very_module = input.parse().unwrap();
+                        let _: Token![=] = input.parse()?;
+                        // `$crate`, from a `macro_rules!` expansion, is a single `Ident`.
+                        let dollar_crate = input.cursor().ident().is_some_and(|(ident, _)| {
+                            ident == "$crate"
+                        });
+                        if input.peek(Token![crate]).not() && dollar_crate.not() {
+                            return Err(input.error("\
+                                path must be absolute and start with `crate::` or `$crate::` \
+                                (instead of `your_crate_name`)\
+                            "));
+                        }
+                        module_path = Some(Path::parse_mod_style(input)?);
+                    },
+                    _case if snoopy.peek(kw::path_to_named_generics_bundle_crate) => {
+                        if krate.is_some() {
+                            return Err(input.error("duplicate entry"));
+                        }
+                        let _: kw::path_to_named_generics_bundle_crate = input.parse().unwrap();
+                        let _: Token![=] = input.parse()?;
+                        krate = Some(Path::parse_mod_style(input)?);
+                    },
+                    _case if snoopy.peek(kw::implied_bounds) => {
+                        if implied_bounds.is_some() {
+                            return Err(input.error("duplicate entry"));
+                        }
+                        let _: kw::implied_bounds = input.parse().unwrap();
+                        let contents;
+                        parenthesized!(contents in input);
+                        let bounds = Punctuated::<Ident, Token![,]>::parse_terminated(&contents)?;
+                        for bound in &bounds {
+                            if IMPLIABLE_BOUNDS.iter().all(|it| bound != it) {
+                                return Err(Error::new_spanned(bound, format_args!(
+                                    "expected one of {}",
+                                    IMPLIABLE_BOUNDS.iter().map(|it| format!("`{it}`")).collect::<Vec<_>>().join(", "),
+                                )));
+                            }
+                        }
+                        implied_bounds = Some(bounds);
+                    },
+                    _case if snoopy.peek(kw::export) => {
+                        if export.is_some() {
+                            return Err(input.error("duplicate entry"));
+                        }
+                        let _: kw::export = input.parse().unwrap();
+                        let _: Token![=] = input.parse()?;
+                        let policy: Ident = input.parse()?;
+                        if ["auto", "always", "never"].iter().all(|it| policy != it) {
+                            return Err(Error::new_spanned(
+                                &policy,
+                                "expected `auto`, `always`, or `never`",
+                            ));
+                        }
+                        export = Some(policy);
+                    },
+                    _case if snoopy.peek(kw::on_unimplemented) => {
+                        if on_unimplemented.is_some() {
+                            return Err(input.error("duplicate entry"));
+                        }
+                        on_unimplemented = Some(input.parse()?);
+                    },
+                    _case if snoopy.peek(kw::sealed) => {
+                        if sealed.is_some() {
+                            return Err(input.error("duplicate entry"));
+                        }
+                        sealed = Some(input.parse()?);
+                    },
+                    _case if snoopy.peek(kw::debug) => {
+                        if debug.is_some() {
+                            return Err(input.error("duplicate entry"));
+                        }
+                        debug = Some(input.parse()?);
+                    },
+                    _case if snoopy.peek(kw::dyn_companion) => {
+                        if dyn_companion.is_some() {
+                            return Err(input.error("duplicate entry"));
+                        }
+                        let kw: kw::dyn_companion = input.parse().unwrap();
+                        if cfg!(feature = "alloc").not() {
+                            return Err(Error::new_spanned(kw, "\
+                                `dyn_companion` requires the `alloc` Cargo feature \
+                                of `named-generics-bundle`\
+                            "));
+                        }
+                        let _: Token![=] = input.parse()?;
+                        dyn_companion = Some(input.parse()?);
+                    },
+                    _case if snoopy.peek(kw::builder) => {
+                        if builder.is_some() {
+                            return Err(input.error("duplicate entry"));
+                        }
+                        let _: kw::builder = input.parse().unwrap();
+                        let _: Token![=] = input.parse()?;
+                        builder = Some(input.parse()?);
+                    },
+                    // Hidden: emitted by the aliases `configure!` defines.
+                    _case if input.peek(kw::ඞdefaults) => {
+                        if defaults.is_some() {
+                            return Err(input.error("duplicate entry"));
+                        }
+                        let _: kw::ඞdefaults = input.parse().unwrap();
+                        let contents;
+                        parenthesized!(contents in input);
+                        defaults = Some(Box::new(contents.parse()?));
+                    },
+                    _default => return Err(snoopy.error()),
+                }
+                let _: Option<Token![,]> = input.parse()?;
+            }
+
+            Ok(Self {
+                module_path,
+                krate,
+                implied_bounds,
+                export,
+                on_unimplemented,
+                sealed,
+                debug,
+                dyn_companion,
+                builder,
+                defaults,
+            })
+        }().map_err(|mut err| {
+            err.combine(Error::new_spanned(err.to_compile_error(), USAGE));
+            err
+        })
+    }
+}
+
+impl Args {
+    /// Merge the args of an extra, inert, `#[named_generics_bundle(…)]` attribute on the trait.
+    pub(crate) fn extend(&mut self, other: Args) -> Result<()> {
+        fn merge<T : ToTokens>(this: &mut Option<T>, other: Option<T>) -> Result<()> {
+            match (&*this, other) {
+                (_, None) => {},
+                (None, other @ Some(_)) => *this = other,
+                (Some(_), Some(dup)) => return Err(Error::new_spanned(dup, "duplicate entry")),
+            }
+            Ok(())
+        }
+        let Args {
+            module_path, krate, implied_bounds, export, on_unimplemented, sealed, debug,
+            dyn_companion, builder, defaults,
+        } = other;
+        merge(&mut self.module_path, module_path)?;
+        merge(&mut self.krate, krate)?;
+        merge(&mut self.implied_bounds, implied_bounds)?;
+        merge(&mut self.export, export)?;
+        merge(&mut self.on_unimplemented, on_unimplemented)?;
+        merge(&mut self.sealed, sealed)?;
+        merge(&mut self.debug, debug)?;
+        merge(&mut self.dyn_companion, dyn_companion)?;
+        merge(&mut self.builder, builder)?;
+        if let Some(defaults) = defaults {
+            match &mut self.defaults {
+                Some(ours) => ours.extend(*defaults)?,
+                ours @ None => *ours = Some(defaults),
+            }
+        }
+        Ok(())
+    }
+
+    /// Fill in whatever has not been explicitly specified with the defaults, if any: those of a
+    /// `named_generics_bundles!` block, and then, the crate-wide ones.
+    pub(crate) fn apply_defaults(&mut self) {
+        if let Some(mut defaults) = self.defaults.take() {
+            defaults.apply_defaults();
+            let Args {
+                module_path, krate, implied_bounds, export, on_unimplemented, sealed, debug,
+                dyn_companion, builder, defaults: _,
+            } = *defaults;
+            self.module_path = self.module_path.take().or(module_path);
+            self.krate = self.krate.take().or(krate);
+            self.implied_bounds = self.implied_bounds.take().or(implied_bounds);
+            self.export = self.export.take().or(export);
+            self.on_unimplemented = self.on_unimplemented.take().or(on_unimplemented);
+            self.sealed = self.sealed.take().or(sealed);
+            self.debug = self.debug.take().or(debug);
+            self.dyn_companion = self.dyn_companion.take().or(dyn_companion);
+            self.builder = self.builder.take().or(builder);
+        }
+    }
+
+    pub(crate) fn export(&self) -> Export {
+        match self.export.as_ref().map(Ident::to_string).as_deref() {
+            None | Some("auto") => Export::Auto,
+            Some("always") => Export::Always,
+            Some("never") => Export::Never,
+            Some(_) => unreachable!("as per the current `Parse` implementation"),
+        }
+    }
+
+    pub(crate) fn implied_bounds(&self) -> Vec<Ident> {
+        match &self.implied_bounds {
+            Some(bounds) => bounds.iter().cloned().collect(),
+            None => {
+                DEFAULT_IMPLIED_BOUNDS
+                    .iter()
+                    .map(|name| Ident::new(name, Span::call_site()))
+                    .collect()
+            },
+        }
+    }
+}
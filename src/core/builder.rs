@@ -0,0 +1,281 @@
+//! `builder = BundleBuilder`: a type-state builder, inferring the field types from the values
+//! it is given.
+//!
+//! Since it involves listing the (`#[cfg]`-enabled) fields in many positions which do not
+//! accept `#[cfg]` attributes, the actual definition is done by the hidden
+//! `bundle_builder!` proc-macro, called back with the enabled fields through a
+//! [`cfg_chain`]:
+//! `[#krate] [#pub_] [#TraitName] [#BuilderName]`
+//! `[#( [#FieldName #kind $(optional)? $(checked)?] )*]`.
+
+use super::*;
+
+mod kw {
+    ::syn::custom_keyword!(value);
+    ::syn::custom_keyword!(type_only);
+    ::syn::custom_keyword!(optional);
+    ::syn::custom_keyword!(checked);
+}
+
+/// The `bundle_builder!` invocation, as emitted alongside the trait.
+pub(crate)
+fn builder(
+    krate: &TokenStream2,
+    pub_: &Visibility,
+    TraitName @ _: &Ident,
+    BuilderName @ _: &Ident,
+    body: &Punctuated<TraitItemType, parse::Nothing>,
+    field_args: &[field_args::FieldArgs],
+    type_checked: &[bool],
+) -> TokenStream2
+{
+    let parts = body.iter().zip(field_args).zip(type_checked).map(|((ty, args), &checked)| {
+        let FieldName @ _ = &ty.ident;
+        let kind = match args.type_only {
+            Some(_) => quote!(type_only),
+            None => quote!(value),
+        };
+        let optional = args.optional.map(|_| quote!(optional));
+        let checked = checked.then(|| quote!(checked));
+        let field = quote!([#FieldName #kind #optional #checked]);
+        match &args.cfg_predicate {
+            None => cfg_chain::Part::Always(field),
+            Some(cfg_predicate) => cfg_chain::Part::Gated {
+                cfg_predicate: cfg_predicate.clone(),
+                present: field,
+                absent: quote!(),
+            },
+        }
+    }).collect();
+    cfg_chain::assemble(&format!("{BuilderName}ඞbuilder"), parts, |fields| quote!(
+        #krate::ඞ::bundle_builder! {
+            [#krate] [#pub_] [#TraitName] [#BuilderName] [#fields]
+        }
+    ))
+}
+
+struct Field {
+    FieldName: Ident,
+    type_only: bool,
+    optional: bool,
+    /// Whether the field has a `ඞTraitඞfields::Field` check.
+    checked: bool,
+}
+
+impl Parse for Field {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let contents;
+        bracketed!(contents in input);
+        let FieldName = contents.parse()?;
+        let type_only = if contents.peek(kw::type_only) {
+            let _: kw::type_only = contents.parse()?;
+            true
+        } else {
+            let _: kw::value = contents.parse()?;
+            false
+        };
+        let optional = contents.parse::<Option<kw::optional>>()?.is_some();
+        let checked = contents.parse::<Option<kw::checked>>()?.is_some();
+        Ok(Self { FieldName, type_only, optional, checked })
+    }
+}
+
+struct Callback {
+    krate: TokenStream2,
+    pub_: Visibility,
+    TraitName: Ident,
+    BuilderName: Ident,
+    fields: Vec<Field>,
+}
+
+impl Parse for Callback {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let (krate, pub_, trait_name, builder_name, fields);
+        bracketed!(krate in input);
+        bracketed!(pub_ in input);
+        bracketed!(trait_name in input);
+        bracketed!(builder_name in input);
+        bracketed!(fields in input);
+        Ok(Self {
+            krate: krate.parse()?,
+            pub_: pub_.parse()?,
+            TraitName: trait_name.parse()?,
+            BuilderName: builder_name.parse()?,
+            fields: {
+                let mut ret = vec![];
+                while fields.is_empty().not() {
+                    ret.push(fields.parse()?);
+                }
+                ret
+            },
+        })
+    }
+}
+
+pub(crate)
+fn bundle_builder_impl(input: TokenStream2) -> Result<TokenStream2> {
+    let Callback { krate, pub_, TraitName, BuilderName, fields } = parse2(input)?;
+    let ඞfields @ _ = &format_ident!("ඞ{BuilderName}ඞfields");
+    let ඞTraitඞfields @ _ = &format_ident!("ඞ{TraitName}ඞfields");
+    let FieldName @ _ = &fields.iter().map(|f| &f.FieldName).collect::<Vec<_>>();
+    let field_name @ _ = &FieldName.iter().map(|it| snake_case(it)).collect::<Vec<_>>();
+    let ඞField @ _ = &FieldName.iter().map(|it| format_ident!("ඞ{it}")).collect::<Vec<_>>();
+    // Hygienic, so as not to clash with the (user-provided) name of the setter argument.
+    let binding @ _ = &field_name.iter().map(|it| {
+        Ident::new(&format!("ඞ{}", it.to_string().trim_start_matches("r#")), Span::mixed_site())
+    }).collect::<Vec<_>>();
+    let Unset @ _ = quote!(#krate::ඞ::Unset);
+    let IsSet @ _ = &quote!(#krate::ඞ::IsSet);
+    let OrAbsent @ _ = &quote!(#krate::ඞ::OrAbsent);
+
+    let setters = fields.iter().enumerate().map(|(i, Field { FieldName, type_only, .. })| {
+        let this = &field_name[i];
+        let OtherField @ _ = all_but(ඞField, i);
+        let Before @ _ = &ඞField[.. i];
+        let After @ _ = &ඞField[i + 1 ..];
+        let other_name @ _ = &all_but(field_name, i);
+        let other @ _ = &all_but(binding, i);
+        let (doc, generic, arg, state, set) = if *type_only {
+            (
+                format!(" Sets the `{FieldName}` field."),
+                quote!(ඞT : ?::core::marker::Sized),
+                quote!(),
+                quote!(#krate::ඞ::SetType<ඞT>),
+                quote!(#krate::ඞ::SetType::new()),
+            )
+        } else {
+            (
+                format!(" Sets the `{FieldName}` field, inferring its type from the given value."),
+                quote!(ඞT),
+                quote!(#this: ඞT),
+                quote!(#krate::ඞ::Set<ඞT>),
+                quote!(#krate::ඞ::Set(#this)),
+            )
+        };
+        quote!(
+            impl<#(#OtherField),*> #BuilderName<#(#Before,)* #Unset, #(#After),*> {
+                #[doc = #doc]
+                pub fn #this<#generic>(self, #arg)
+                  -> #BuilderName<#(#Before,)* #state, #(#After),*>
+                {
+                    let Self { #(#other_name: #other,)* .. } = self;
+                    #BuilderName {
+                        #(#other_name: #other,)*
+                        #this: #set,
+                    }
+                }
+            }
+        )
+    });
+
+    // Optional fields may be left unset (and thus `Absent`).
+    let State @ _ = &fields.iter().zip(ඞField).map(|(f, ඞField)| match f.optional {
+        true => quote!(<#ඞField as #OrAbsent>),
+        false => {
+            let FieldName @ _ = &f.FieldName;
+            quote!(<#ඞField as #IsSet<#ඞfields::#FieldName>>)
+        },
+    }).collect::<Vec<_>>();
+    let binding_of_the_flag = fields.iter().zip(State).filter(|(f, _)| f.optional).map(
+        |(f, State)| {
+            let Flag @ _ = presence_flag(&f.FieldName);
+            quote!(#Flag = #State::Presence,)
+        },
+    );
+    let bound = fields.iter().zip(ඞField).map(|(f, ඞField)| match f.optional {
+        true => quote!(#ඞField : #OrAbsent,),
+        false => {
+            let FieldName @ _ = &f.FieldName;
+            quote!(#ඞField : #IsSet<#ඞfields::#FieldName>,)
+        },
+    });
+    // The eponymous macro may check the field types against their bounds right away.
+    let checked_bound = fields.iter().zip(State).filter(|(f, _)| f.checked).map(|(f, State)| {
+        let FieldName @ _ = &f.FieldName;
+        quote!(#State::Type : #ඞTraitඞfields::#FieldName,)
+    });
+    // Fields set through a type have no value to be returned.
+    let ValueState @ _ =
+        fields.iter().zip(State).filter(|(f, _)| f.type_only.not()).map(|(_, it)| it)
+    ;
+    let ValueState2 @ _ = ValueState.clone();
+    let value_binding @ _ =
+        fields.iter().zip(binding).filter(|(f, _)| f.type_only.not()).map(|(_, it)| it)
+    ;
+    let doc = format!(
+        " Type-state builder of [`{TraitName}`](trait@{TraitName}) bundles, inferring each field \
+        type from the given value.",
+    );
+    let build_doc = format!(
+        " The `{TraitName}![…]` bundle (as a value), along with the values of the fields, in \
+        order.",
+    );
+    Ok(quote!(
+        #[doc(hidden)]
+        #[allow(nonstandard_style)]
+        #pub_ mod #ඞfields {
+            #(
+                pub struct #FieldName;
+            )*
+        }
+
+        #[doc = #doc]
+        #[must_use]
+        #pub_
+        struct #BuilderName<#(#ඞField = #Unset),*> {
+            #(
+                #field_name: #ඞField,
+            )*
+        }
+
+        impl #BuilderName {
+            /// A builder with every field unset.
+            pub fn new() -> Self {
+                Self {
+                    #(
+                        #field_name: #krate::ඞ::Unset,
+                    )*
+                }
+            }
+        }
+
+        impl #krate::ඞ::core::default::Default for #BuilderName {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        #(#setters)*
+
+        impl<#(#ඞField),*> #BuilderName<#(#ඞField),*> {
+            #[doc = #build_doc]
+            pub fn build(self) -> (
+                #TraitName![
+                    #(
+                        #FieldName = #State::Type,
+                    )*
+                    #(#binding_of_the_flag)*
+                ],
+                #(
+                    #ValueState::Value,
+                )*
+            )
+            where
+                #(#bound)*
+                #(#checked_bound)*
+            {
+                let Self { #(#field_name: #binding,)* } = self;
+                (
+                    #krate::ඞ::core::marker::PhantomData,
+                    #(
+                        #ValueState2::value(#value_binding),
+                    )*
+                )
+            }
+        }
+    ))
+}
+
+fn all_but(items: &[Ident], i: usize) -> Vec<&Ident> {
+    items.iter().enumerate().filter(|&(j, _)| j != i).map(|(_, it)| it).collect()
+}
@@ -0,0 +1,82 @@
+//! `named_generics_bundles! { … }`: the function-like flavor of the attribute, for bulk usage.
+
+use super::*;
+
+/// ```rust ,ignore
+/// $(#![named_generics_bundle($($shared_args:tt)*)])*
+/// $(
+///     $(#[$attr:meta])*
+///     $pub:vis
+///     trait $TraitName:ident …
+///     {
+///         …
+///     }
+/// )*
+/// ```
+struct Bundles {
+    shared_args: Vec<TokenStream2>,
+    traits: Vec<TokenStream2>,
+}
+
+impl Parse for Bundles {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut shared_args = vec![];
+        for attr in Attribute::parse_inner(input)? {
+            if attr.path().is_ident("named_generics_bundle").not() {
+                return Err(Error::new_spanned(
+                    attr,
+                    "expected `#![named_generics_bundle(…)]`",
+                ));
+            }
+            shared_args.push(match attr.meta {
+                Meta::Path(_) => quote!(),
+                meta => meta.require_list()?.tokens.clone(),
+            });
+        }
+        let mut traits = vec![];
+        while input.is_empty().not() {
+            // Every trait definition ends with its braced body.
+            traits.push(input.step(|cursor| {
+                let mut tts = vec![];
+                let mut rest = *cursor;
+                while let Some((tt, next)) = rest.token_tree() {
+                    rest = next;
+                    let is_body = matches!(
+                        &tt,
+                        TT::Group(g) if g.delimiter() == Delimiter::Brace,
+                    );
+                    tts.push(tt);
+                    if is_body {
+                        return Ok((tts.into_iter().collect::<TokenStream2>(), rest));
+                    }
+                }
+                Err(cursor.error("expected a `trait` definition"))
+            })?);
+        }
+        Ok(Self { shared_args, traits })
+    }
+}
+
+pub(crate)
+fn named_generics_bundles_impl(input: TokenStream2) -> Result<TokenStream2> {
+    let Bundles { shared_args, traits } = parse2(input)?;
+    // The block-wide args act as defaults for each trait in it.
+    let args = quote!(
+        ඞdefaults(#(#shared_args),*)
+    );
+    let mut ret = TokenStream2::new();
+    let mut errors: Option<Error> = None;
+    for trait_ in traits {
+        match named_generics_bundle_impl(args.clone(), trait_) {
+            Ok(expansion) => ret.extend(expansion),
+            Err(err) => match &mut errors {
+                Some(errors) => errors.combine(err),
+                None => errors = Some(err),
+            },
+        }
+    }
+    match errors {
+        Some(err) => Err(err),
+        None => Ok(ret),
+    }
+}
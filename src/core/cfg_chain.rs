@@ -0,0 +1,83 @@
+//! Assembling tokens out of `#[cfg]`-dependent parts, for the syntactic positions which do not
+//! accept `#[cfg]` attributes (such as `where` clauses, or `macro_rules!` arms).
+//!
+//! This is achieved through a chain of helper `macro_rules!`, each of them defined twice, under
+//! `#[cfg(…)]` and `#[cfg(not(…))]` respectively, and picking the corresponding part.
+
+use super::*;
+
+pub(crate) enum Part {
+    Always(TokenStream2),
+    Gated {
+        cfg_predicate: TokenStream2,
+        present: TokenStream2,
+        absent: TokenStream2,
+    },
+}
+
+/// `finish` is given the assembled tokens, and must not otherwise involve any `$`.
+pub(crate)
+fn assemble(
+    name: &str,
+    parts: Vec<Part>,
+    finish: impl FnOnce(TokenStream2) -> TokenStream2,
+) -> TokenStream2
+{
+    if parts.iter().all(|part| matches!(part, Part::Always(_))) {
+        let acc = parts.into_iter().map(|part| match part {
+            Part::Always(tts) => tts,
+            Part::Gated { .. } => unreachable!(),
+        });
+        return finish(quote!(#(#acc)*));
+    }
+    let definer = |i: usize| format_ident!("__ඞ{name}ඞcfg_{i}");
+    let mut ret = quote!();
+    let mut groups = quote!();
+    let count = parts.len();
+    for (i, part) in parts.into_iter().enumerate() {
+        let (cfg_predicate, present, absent) = match part {
+            Part::Always(tts) => (quote!(all()), tts.clone(), tts),
+            Part::Gated { cfg_predicate, present, absent } => (cfg_predicate, present, absent),
+        };
+        let this = definer(i);
+        let next = definer(i + 1);
+        for (cfg, picked) in [
+            (quote!(#cfg_predicate), quote!($($present)*)),
+            (quote!(not(#cfg_predicate)), quote!($($absent)*)),
+        ] {
+            ret.extend(quote!(
+                #[cfg(#cfg)]
+                #[doc(hidden)]
+                macro_rules! #this {(
+                    [$($acc:tt)*]
+                    [$($present:tt)*]
+                    [$($absent:tt)*]
+                    $($rest:tt)*
+                ) => (
+                    #next! {
+                        [$($acc)* #picked]
+                        $($rest)*
+                    }
+                )}
+            ));
+        }
+        groups.extend(quote!([#present] [#absent]));
+    }
+    let first = definer(0);
+    let last = definer(count);
+    let finish = finish(quote!($($acc)*));
+    ret.extend(quote!(
+        #[doc(hidden)]
+        macro_rules! #last {(
+            [$($acc:tt)*]
+        ) => (
+            #finish
+        )}
+
+        #first! {
+            []
+            #groups
+        }
+    ));
+    ret
+}
@@ -0,0 +1,138 @@
+//! `configure! { … }`: declare, once, crate-wide defaults for the attribute args; and
+//! `#[apply(alias!)]`, to use the resulting alias as an attribute.
+
+use super::*;
+
+mod kw {
+    ::syn::custom_keyword!(macro_rules);
+}
+
+/// ```rust ,ignore
+/// $(#[$attr:meta])*
+/// macro_rules! $name:ident {
+///     $($args:tt)*
+/// }
+/// ```
+struct Configure {
+    attrs: Vec<Attribute>,
+    name: Ident,
+    args: TokenStream2,
+}
+
+impl Parse for Configure {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let attrs = Attribute::parse_outer(input)?;
+        let _: kw::macro_rules = input.parse()?;
+        let _: Token![!] = input.parse()?;
+        let name = input.parse()?;
+        let args = input.step(|cursor| match cursor.token_tree() {
+            Some((TT::Group(g), rest)) if g.delimiter() != Delimiter::None => {
+                Ok((g.stream(), rest))
+            },
+            _ => Err(cursor.error("expected `{ … }`")),
+        })?;
+        let _: Option<Token![;]> = input.parse()?;
+        Ok(Self { attrs, name, args })
+    }
+}
+
+pub(crate)
+fn configure_impl(input: TokenStream2) -> Result<TokenStream2> {
+    let Configure { attrs, name, args } = parse2(input)?;
+
+    // Since we are to be used from within a `macro_rules!` definition, `$crate` is allowed,
+    // and `crate` is to be understood as such.
+    let args = strip_dollar_crate(args);
+    let parsed: args::Args = parse2(args.clone())?;
+    if let Some(module_path) = &parsed.module_path {
+        return Err(Error::new_spanned(
+            module_path,
+            "`path_to_this_very_module` cannot be a crate-wide default",
+        ));
+    }
+    if let Some(dyn_companion) = &parsed.dyn_companion {
+        return Err(Error::new_spanned(
+            dyn_companion,
+            "`dyn_companion` cannot be a crate-wide default",
+        ));
+    }
+    if let Some(builder) = &parsed.builder {
+        return Err(Error::new_spanned(
+            builder,
+            "`builder` cannot be a crate-wide default",
+        ));
+    }
+    let krate = parsed.krate.as_ref().map_or_else(
+        || quote!(::named_generics_bundle),
+        |krate| lift_crate_to_dollar_crate(krate.to_token_stream()),
+    );
+    let args = lift_crate_to_dollar_crate(args);
+
+    Ok(quote!(
+        #(#attrs)*
+        macro_rules! #name {(
+            $($input:tt)*
+        ) => (
+            #krate::named_generics_bundles! {
+                #![named_generics_bundle(ඞdefaults(#args))]
+                $($input)*
+            }
+        )}
+    ))
+}
+
+/// `#[apply(#alias!)] #input` becomes `#alias! { #input }`.
+pub(crate)
+fn apply_impl(args: TokenStream2, input: TokenStream2) -> Result<TokenStream2> {
+    let alias = Parser::parse2(
+        |input: ParseStream<'_>| {
+            let alias = Path::parse_mod_style(input)?;
+            let bang: Token![!] = input.parse()?;
+            Ok(quote!(#alias #bang))
+        },
+        args,
+    )?;
+    Ok(quote!(
+        #alias {
+            #input
+        }
+    ))
+}
+
+fn strip_dollar_crate(ts: TokenStream2) -> TokenStream2 {
+    let mut tts = ts.into_iter().peekable();
+    let mut ret = vec![];
+    while let Some(tt) = tts.next() {
+        match tt {
+            TT::Punct(p)
+                if p.as_char() == '$'
+                && matches!(tts.peek(), Some(TT::Ident(krate)) if krate == "crate")
+            => {},
+            TT::Group(g) => {
+                let mut new = Group::new(g.delimiter(), strip_dollar_crate(g.stream()));
+                new.set_span(g.span());
+                ret.push(TT::Group(new));
+            },
+            _ => ret.push(tt),
+        }
+    }
+    ret.into_iter().collect()
+}
+
+/// Lift `crate` to `$crate`, to be used in a `macro_rules!` definition.
+pub(crate)
+fn lift_crate_to_dollar_crate(ts: TokenStream2) -> TokenStream2 {
+    ts.into_iter().flat_map(|tt| match tt {
+        TT::Ident(krate) if krate == "crate" => {
+            let mut dollar = Punct::new('$', Spacing::Joint);
+            dollar.set_span(krate.span());
+            vec![dollar.into(), krate.into()]
+        },
+        TT::Group(g) => {
+            let mut new = Group::new(g.delimiter(), lift_crate_to_dollar_crate(g.stream()));
+            new.set_span(g.span());
+            vec![new.into()]
+        },
+        _ => vec![tt],
+    }).collect()
+}
@@ -0,0 +1,42 @@
+//! `#[named_generics_bundle(debug)]`: report the expansion, as a warning.
+//!
+//! The warning is that of the use of a `#[deprecated]` item, spanned on the `debug` arg, whose
+//! `note` is the (pretty-printed, under the `debug` feature) expansion. The expansion itself
+//! is emitted unchanged, so that the build goes on as it would have without `debug`.
+
+use super::*;
+
+pub(crate)
+fn dump(debug: &args::kw::debug, TraitName @ _: &Ident, expansion: TokenStream2) -> TokenStream2 {
+    let note = format!(
+        "`#[named_generics_bundle(debug)]` expansion of `{TraitName}`:\n\n{}",
+        pretty_print(&expansion),
+    );
+    let debug = Ident::new("debug", debug.span);
+    quote!(
+        #expansion
+
+        const _: () = {
+            #[deprecated(note = #note)]
+            #[allow(nonstandard_style)]
+            struct #debug;
+
+            let _ = #debug;
+        };
+    )
+}
+
+#[cfg(feature = "debug")]
+fn pretty_print(expansion: &TokenStream2) -> String {
+    match parse2(expansion.clone()) {
+        Ok(file) => ::prettyplease::unparse(&file),
+        Err(_) => expansion.to_string(),
+    }
+}
+
+#[cfg(not(feature = "debug"))]
+fn pretty_print(expansion: &TokenStream2) -> String {
+    format!(
+        "{expansion}\n\n(enable the `debug` Cargo feature of `named-generics-bundle` for a pretty-printed expansion)",
+    )
+}
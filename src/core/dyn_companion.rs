@@ -0,0 +1,227 @@
+//! `dyn_companion = DynBundle`: a type-erased instance of the bundle, with `Box<dyn …>` fields,
+//! for the generic code to run fully dynamically.
+//!
+//! `Box<dyn Bound> : Bound` requires a hand-written forwarding impl (the methods of `Bound` being
+//! unknown to us), so its absence is reported through an `ඞFieldErased` helper trait, required by
+//! the `impl`, and whose `ItSelf` projection carries the field bounds (so that the `impl` body
+//! raises no other error).
+
+use super::*;
+
+/// Auto traits, which may be added to the `dyn` of an erased field.
+const AUTO_TRAITS: &[&str] = &["Send", "Sync", "Unpin", "UnwindSafe", "RefUnwindSafe"];
+
+/// Common stdlib traits which are not `dyn`-compatible.
+const DYN_INCOMPATIBLE_TRAITS: &[&str] = &[
+    "Sized",
+    "Clone", "Copy",
+    "PartialEq", "Eq", "PartialOrd", "Ord",
+    "Hash",
+    "Default",
+    "From", "Into", "TryFrom", "TryInto", "FromStr",
+    "FromIterator", "Extend",
+];
+
+pub(crate)
+fn dyn_companion(
+    krate: &TokenStream2,
+    pub_: &Visibility,
+    TraitName @ _: &Ident,
+    DynTraitName @ _: &Ident,
+    body: &Punctuated<TraitItemType, parse::Nothing>,
+    field_args: &[field_args::FieldArgs],
+) -> Result<TokenStream2>
+{
+    let mut errors = vec![];
+    let mut gated_errors = quote!();
+    let mut failing_cfg_predicates = vec![];
+    let mut assoc_types = quote!();
+    let mut erased_checks = quote!();
+    let mut where_clauses = vec![];
+    for (ty, args) in body.iter().zip(field_args) {
+        let FieldName @ _ = &ty.ident;
+        let cfg_attrs = &args.cfg_attrs;
+        match (erase(krate, DynTraitName, ty), &args.cfg_predicate) {
+            (Ok((mut Erased, principal)), _) => {
+                if let Some(principal) = principal {
+                    // Spanned on the `Bound`, so is the `where` clause below.
+                    let ඞFieldErased @ _ =
+                        Ident::new(&format!("ඞ{FieldName}Erased"), principal.span());
+                    let ඞFieldBox @ _ = Ident::new(&format!("ඞ{FieldName}Box"), principal.span());
+                    let bounds = ty.bounds.iter().filter(|bound| matches!(
+                        bound,
+                        TypeParamBound::Trait(TraitBound {
+                            modifier: TraitBoundModifier::Maybe(_),
+                            ..
+                        }),
+                    ).not()).collect::<Vec<_>>();
+                    let principal_str = pretty_tokens(principal);
+                    let message = format!(
+                        "`{{Self}}` does not implement `{principal_str}`, \
+                        as needed by the `{FieldName}` field of `{DynTraitName}`",
+                    );
+                    let label = format!("`{FieldName}` is type-erased as `{{Self}}`");
+                    let note = format!(
+                        "add a forwarding `impl<T : ?Sized + {principal_str}> \
+                        {principal_str} for Box<T>` (delegating to `**self`)",
+                    );
+                    erased_checks.extend(quote!(
+                        #(#cfg_attrs)*
+                        #[diagnostic::on_unimplemented(
+                            message = #message,
+                            label = #label,
+                            note = #note,
+                        )]
+                        pub trait #ඞFieldErased {
+                            type ItSelf : #(#bounds +)*;
+                        }
+
+                        #(#cfg_attrs)*
+                        #[diagnostic::do_not_recommend]
+                        impl<ඞT : #(#bounds +)*> #ඞFieldErased for ඞT {
+                            type ItSelf = ඞT;
+                        }
+
+                        #(#cfg_attrs)*
+                        pub type #ඞFieldBox = #Erased;
+                    ));
+                    let where_clause = quote!(
+                        #ඞFieldBox : #ඞFieldErased,
+                    );
+                    where_clauses.push(match &args.cfg_predicate {
+                        None => cfg_chain::Part::Always(where_clause),
+                        Some(cfg_predicate) => cfg_chain::Part::Gated {
+                            cfg_predicate: cfg_predicate.clone(),
+                            present: where_clause,
+                            absent: quote!(),
+                        },
+                    });
+                    Erased = quote!(<#Erased as #ඞFieldErased>::ItSelf);
+                }
+                assoc_types.extend(quote!(
+                    #(#cfg_attrs)*
+                    type #FieldName = #Erased;
+                ));
+                if args.optional.is_some() {
+                    let Flag @ _ = presence_flag(FieldName);
+                    assoc_types.extend(quote!(
+                        #(#cfg_attrs)*
+                        type #Flag = #krate::ඞ::Present;
+                    ));
+                }
+            },
+            (Err(errs), None) => errors.extend(errs),
+            // Only to be emitted when the field is enabled.
+            (Err(errs), Some(cfg_predicate)) => {
+                let errs = errs.iter().map(Error::to_compile_error);
+                gated_errors.extend(quote!(
+                    #[cfg(#cfg_predicate)]
+                    const _: () = { #(#errs)* };
+                ));
+                failing_cfg_predicates.push(cfg_predicate);
+            },
+        }
+    }
+    let mut errors = errors.into_iter();
+    if let Some(mut err) = errors.next() {
+        errors.for_each(|cur| err.combine(cur));
+        return Err(err);
+    }
+
+    let doc = format!(
+        " Type-erased [`{TraitName}`](trait@{TraitName}) bundle: every field is a `Box<dyn …>`.",
+    );
+    let std_derives = impl_bundle::std_derives();
+    let impl_ = cfg_chain::assemble(
+        &format!("{DynTraitName}ඞimpl"),
+        where_clauses,
+        |where_clauses| quote!(
+            #[cfg(not(any(#(#failing_cfg_predicates),*)))]
+            impl #TraitName for #DynTraitName
+            where
+                #where_clauses
+            {
+                #assoc_types
+            }
+        ),
+    );
+    Ok(quote!(
+        #[doc = #doc]
+        #std_derives
+        #pub_
+        struct #DynTraitName;
+
+        const _: () = {
+            #erased_checks
+
+            #impl_
+        };
+
+        #gated_errors
+    ))
+}
+
+/// `Box<dyn Bound + AutoTraits…>` (along with that `Bound`, unless it be the default `Any`), or
+/// why the field cannot be erased.
+fn erase<'ty>(
+    krate: &TokenStream2,
+    DynTraitName @ _: &Ident,
+    ty: &'ty TraitItemType,
+) -> ::core::result::Result<(TokenStream2, Option<&'ty TypeParamBound>), Vec<Error>>
+{
+    let FieldName @ _ = &ty.ident;
+    let cannot_be_erased = |spanned: &dyn ToTokens, reason: &str| Error::new_spanned(
+        spanned,
+        format_args!("`{FieldName}` cannot be type-erased (in `{DynTraitName}`): {reason}"),
+    );
+    if ty.generics.params.is_empty().not() {
+        return Err(vec![cannot_be_erased(&ty.generics, "it is a generic associated type")]);
+    }
+    let mut errors = vec![];
+    let mut principal = vec![];
+    let mut auto_traits = vec![];
+    for bound in &ty.bounds {
+        match bound {
+            TypeParamBound::Trait(TraitBound {
+                modifier: TraitBoundModifier::Maybe(_),
+                ..
+            }) => {},
+            TypeParamBound::Lifetime(_) => auto_traits.push(bound),
+            TypeParamBound::Trait(TraitBound { path, .. }) => {
+                let name = &*path.segments.last().unwrap().ident.to_string();
+                if AUTO_TRAITS.contains(&name) {
+                    auto_traits.push(bound);
+                } else if DYN_INCOMPATIBLE_TRAITS.contains(&name) {
+                    errors.push(cannot_be_erased(
+                        bound,
+                        &format!("`{name}` is not `dyn`-compatible"),
+                    ));
+                } else {
+                    principal.push(bound);
+                }
+            },
+            _ => errors.push(cannot_be_erased(bound, "unsupported bound")),
+        }
+    }
+    if let [_, extra, ..] = &principal[..] {
+        errors.push(cannot_be_erased(extra, &format!(
+            "`dyn` supports a single non-auto trait, but got `{}`",
+            principal.iter().map(pretty_tokens).collect::<Vec<_>>().join(" + "),
+        )));
+    }
+    if errors.is_empty().not() {
+        return Err(errors);
+    }
+    // Unbounded fields can still be erased, and downcast back, through `dyn Any`.
+    let principal = principal.first().copied();
+    let Principal @ _ = principal.map_or_else(
+        || quote!(#krate::ඞ::core::any::Any),
+        ToTokens::to_token_stream,
+    );
+    Ok((
+        quote!(
+            #krate::ඞ::Box<dyn #Principal #(+ #auto_traits)*>
+        ),
+        principal,
+    ))
+}
@@ -0,0 +1,94 @@
+//! Up-front `dyn`-compatibility checks of the fields, since `Bundle![…]` expands to a
+//! `dyn Bundle<…>`: an incompatible field would otherwise only be reported (as an `E0038`
+//! pointing into the generated code) at the first invocation of the eponymous macro.
+//!
+//! Only the bundle trait itself needs to be `dyn`-compatible (the traits in the field bounds
+//! need not), which leaves three culprits, all of them detectable syntactically:
+//!   - generic associated types;
+//!   - `where` clauses on the fields (such as `where Self : Sized`, or even
+//!     `where Self::Field : Clone`), which the `dyn Bundle<…>` encoding cannot honor;
+//!   - `Self` used as a generic parameter of a bound (_e.g._, `PartialEq<Self>`), rather than
+//!     through a projection (`Self::Field`), or as an associated type binding (`Item = Self`).
+
+use super::*;
+
+/// Errors out on the incompatible fields, except for the `#[cfg]`-gated ones, whose errors are
+/// returned, to be emitted only when these fields are enabled.
+pub(crate)
+fn check(
+    TraitName @ _: &Ident,
+    body: &Punctuated<TraitItemType, parse::Nothing>,
+    field_args: &[field_args::FieldArgs],
+) -> Result<TokenStream2>
+{
+    let mut errors = vec![];
+    let mut gated_errors = quote!();
+    for (ty, args) in body.iter().zip(field_args) {
+        let errs = field_errors(TraitName, ty);
+        match &args.cfg_predicate {
+            None => errors.extend(errs),
+            Some(_) if errs.is_empty() => {},
+            Some(cfg_predicate) => {
+                let errs = errs.iter().map(Error::to_compile_error);
+                gated_errors.extend(quote!(
+                    #[cfg(#cfg_predicate)]
+                    const _: () = { #(#errs)* };
+                ));
+            },
+        }
+    }
+    let mut errors = errors.into_iter();
+    if let Some(mut err) = errors.next() {
+        errors.for_each(|cur| err.combine(cur));
+        return Err(err);
+    }
+    Ok(gated_errors)
+}
+
+fn field_errors(TraitName @ _: &Ident, ty: &TraitItemType) -> Vec<Error> {
+    let FieldName @ _ = &ty.ident;
+    let incompatible = |spanned: &dyn ToTokens, reason: &str| Error::new_spanned(
+        spanned,
+        format_args!(
+            "`{FieldName}` makes `{TraitName}` `dyn`-incompatible, which `{TraitName}![…]` \
+            requires: {reason}",
+        ),
+    );
+    let mut errors = vec![];
+    if ty.generics.params.is_empty().not() {
+        errors.push(incompatible(&ty.generics, "it is a generic associated type"));
+    }
+    // Even `where Self::Field : Bound` cannot be honored by the `dyn Bundle<…>` encoding.
+    if let Some(where_clause) = &ty.generics.where_clause {
+        errors.push(incompatible(
+            where_clause,
+            "`where` clauses on fields are not supported (bound the field itself instead)",
+        ));
+    }
+    let self_as_parameter = |arg: &Type| mentions_non_projection_self(arg.to_token_stream());
+    for bound in &ty.bounds {
+        let TypeParamBound::Trait(TraitBound { path, .. }) = bound else {
+            continue;
+        };
+        let args = path.segments.iter().flat_map(|segment| match &segment.arguments {
+            PathArguments::None => vec![],
+            PathArguments::AngleBracketed(args) => {
+                args.args.iter().filter_map(|arg| match arg {
+                    GenericArgument::Type(arg) => Some(arg),
+                    // `Item = Self` bindings are fine.
+                    _ => None,
+                }).collect()
+            },
+            // `Fn(…) -> Self` is fine, but the inputs are generic parameters.
+            PathArguments::Parenthesized(args) => args.inputs.iter().collect(),
+        });
+        for arg in args.filter(|arg| self_as_parameter(arg)) {
+            errors.push(incompatible(
+                arg,
+                "`Self` cannot be used as a generic parameter of a bound (only through \
+                `Self::…` projections)",
+            ));
+        }
+    }
+    errors
+}
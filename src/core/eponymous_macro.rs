@@ -0,0 +1,489 @@
+//! The `Bundle![…]` eponymous `macro_rules!` definition.
+//!
+//! It munches the `Key = Type` pairs it is given into per-field slots, so as to handle the
+//! field-specific keys (such as aliases), as well as `..Base` presets, before emitting the
+//! `PhantomData<fn(()) -> dyn Bundle<(), …>>` type.
+//!
+//! The munching state is `@ඞmunch [$($Base)?] [$($extra_bindings)*] [Field0 …] [Field1 …] …`,
+//! followed by the slots of the hidden presence flags of the `#[bundle(optional)]` fields, and
+//! the remaining `Key [Key = Type]` pairs (the keys having been duplicated, in one go, by the
+//! `bundle_keys!` helper, or, for an exported macro lacking a `path_to_this_very_module` to reach
+//! the latter through, by some `@ඞkeys` arms).
+
+use super::*;
+
+pub(crate) struct EponymousMacro<'r> {
+    pub(crate) krate: &'r TokenStream2,
+    /// How the module of the trait refers to the crate (see [`validate_crate_path`]).
+    pub(crate) local_krate: &'r TokenStream2,
+    pub(crate) TraitName: &'r Ident,
+    pub(crate) ඞTraitName: &'r Ident,
+    /// How the eponymous macro is to refer back to itself.
+    pub(crate) macro_self_path: TokenStream2,
+    pub(crate) mb_module_path: &'r [TT],
+    /// The hidden module of the `$T`-spanned field checks (see [`field_checks`]).
+    pub(crate) fields_module: &'r Ident,
+    /// Which fields have such a check.
+    pub(crate) type_checked: &'r [bool],
+    pub(crate) body: &'r Punctuated<TraitItemType, parse::Nothing>,
+    pub(crate) field_args: &'r [field_args::FieldArgs],
+    /// The module of the param-less `dyn` companion of the trait, under `cfg(doc)` (if any).
+    pub(crate) doc_dyn_module: Option<&'r Ident>,
+    /// Whether the macro is `#[macro_export]`ed (and thus usable from other crates).
+    pub(crate) macro_export: bool,
+    /// Docs, `#[macro_export]`, etc.
+    pub(crate) macro_attrs: TokenStream2,
+}
+
+impl EponymousMacro<'_> {
+    pub(crate) fn definition(&self) -> TokenStream2 {
+        let &Self {
+            krate,
+            local_krate,
+            TraitName,
+            ඞTraitName,
+            ref macro_self_path,
+            mb_module_path,
+            fields_module,
+            type_checked,
+            body,
+            field_args,
+            doc_dyn_module,
+            macro_export,
+            ref macro_attrs,
+        } = self;
+
+        let FieldName @ _ = &body.iter().map(|ty| &ty.ident).collect::<Vec<_>>();
+        let EachCfgAttr @ _ = &field_args.iter().map(|args| &args.cfg_attrs).collect::<Vec<_>>();
+        // The optional fields, along with the index of the slot of their presence flag.
+        let optional = body.iter().zip(field_args).enumerate().filter(|(_, (_, args))| {
+            args.optional.is_some()
+        }).enumerate().map(|(k, (i, (ty, _)))| (i, body.len() + k, presence_flag(&ty.ident)));
+        let optional = &optional.collect::<Vec<_>>();
+        let SlotName @ _ =
+            &FieldName.iter().copied().chain(optional.iter().map(|(_, _, Flag)| Flag))
+                .collect::<Vec<_>>()
+        ;
+        let slot @ _ = &(0..SlotName.len()).map(|i| format_ident!("slot{i}")).collect::<Vec<_>>();
+        let OptionalFieldName @ _ =
+            &optional.iter().map(|&(i, _, _)| FieldName[i]).collect::<Vec<_>>()
+        ;
+        let OptionalFlag @ _ = optional.iter().map(|(_, _, Flag)| Flag);
+        let fields_info = quote!(
+            [#(#mb_module_path)* #TraitName]
+            [#(#(#EachCfgAttr)* #FieldName)*]
+            [#(#OptionalFieldName)*]
+        );
+
+        // The binding of the `i`-th slot, as set through `Key`: that of the user's own `$key`
+        // token whenever possible, so that, _e.g._, deprecation warnings point at it.
+        let binding_name = |Key @ _: &Ident, i: usize| match SlotName[i] {
+            SlotName if SlotName == Key => quote!($key),
+            SlotName => SlotName.to_token_stream(),
+        };
+
+        // `$T`, checked against the bounds of the `i`-th field, when possible, so that any
+        // error be spanned on it (which requires the module of the trait to be known).
+        let checked = |i: usize, T @ _: &TokenStream2| {
+            if mb_module_path.is_empty() || type_checked.get(i) != Some(&true) {
+                return T.clone();
+            }
+            let ඞField @ _ = format_ident!("ඞ{}", FieldName[i]);
+            quote!(
+                <() as #(#mb_module_path)* #fields_module::#ඞField<#T>>::ItSelf
+            )
+        };
+
+        // `Key = $T` arm(s), feeding the `i`-th slot (or none, to discard it), and overwriting
+        // the `replaced` one (if any) with the given value. `$T` may be narrowed down to a given
+        // `(pattern, transcription)` pair.
+        //
+        // Since `[Field]` slots (still unset) become `{Field = …,}` ones once set (so that the
+        // finishing arms may tell them apart without ambiguity), the `i`-th slot gets an arm for
+        // either case (the latter, for repeated keys, which the `dyn` type is to report).
+        let slot_arms = |
+            Key @ _: &Ident,
+            i: Option<usize>,
+            replaced: Option<(usize, TokenStream2)>,
+            (T_pattern, T): (TokenStream2, TokenStream2),
+        | {
+            let variants = [
+                (quote!([$unset:ident]), quote!()),
+                (quote!({$($set:tt)*}), quote!($($set)*)),
+            ];
+            let variants = &variants[.. if i.is_some() { 2 } else { 1 }];
+            variants.iter().map(|(slot_pattern, previous)| {
+                let (pattern, transcription) = slot.iter().enumerate().map(|(j, slot)| {
+                    let SlotName @ _ = SlotName[j];
+                    match &replaced {
+                        _ if Some(j) == i => {
+                            let Binding @ _ = binding_name(Key, j);
+                            let T @ _ = checked(j, &T);
+                            (slot_pattern.clone(), quote!({#previous #Binding = #T,}))
+                        },
+                        Some((k, value)) if *k == j => (
+                            quote!($#slot:tt),
+                            quote!({#SlotName = #value,}),
+                        ),
+                        _ => (quote!($#slot:tt), quote!($#slot)),
+                    }
+                }).unzip::<_, _, Vec<_>, Vec<_>>();
+                quote!(
+                    (
+                        @ඞmunch $base:tt $extra:tt #(#pattern)*
+                        #Key [$key:tt = #T_pattern] $(, $($rest:tt)*)?
+                    ) => (
+                        #macro_self_path! {
+                            @ඞmunch $base $extra #(#transcription)*
+                            $($($rest)*)?
+                        }
+                    );
+                )
+            }).collect::<TokenStream2>()
+        };
+        let key_arm = |Key @ _: &Ident, i, replaced| {
+            slot_arms(Key, i, replaced, (quote!($($T:tt)+), quote!($($T)+)))
+        };
+
+        // The helpers of the crate, which, for an exported macro, are to be reached through the
+        // module of the trait (whenever known), since `#krate` need not resolve at the call site.
+        let mut helpers_reexports = quote!();
+        let mut helper = |name: &str| {
+            let name = format_ident!("{name}");
+            if mb_module_path.is_empty() {
+                return quote!(#krate::ඞ::#name);
+            }
+            let ඞname = format_ident!("ඞ{TraitName}ඞ{name}");
+            helpers_reexports.extend(quote!(
+                #[doc(hidden)]
+                pub use #local_krate::ඞ::#name as #ඞname;
+            ));
+            quote!(#(#mb_module_path)* #ඞname)
+        };
+        let [Present, Absent, NotPresent] = if optional.is_empty() {
+            Default::default()
+        } else {
+            ["Present", "Absent", "NotPresent"].map(&mut helper)
+        };
+
+        // The duplication of the keys, through the `bundle_keys!` helper, or, for an exported
+        // macro lacking a module path, through (per-key) munching arms instead (at the cost of
+        // the diagnostics about the bindings then being spanned on the macro when used in the
+        // crate defining it).
+        let bundle_keys_arm = |bundle_keys: TokenStream2| quote!(
+            (
+                $($named_generics:tt)*
+            ) => (
+                #bundle_keys! {
+                    [#macro_self_path]
+                    [@ඞmunch [] [] #([#SlotName])*]
+                    $($named_generics)*
+                }
+            );
+        );
+        let bundle_keys = match (mb_module_path, macro_export) {
+            ([], true) =>                 quote!(
+                    (
+                        @ඞkeys [$($acc:tt)*] .. $Base:ty $(, $($rest:tt)*)?
+                    ) => (
+                        #macro_self_path! {
+                            @ඞkeys [$($acc)* .. $Base,] $($($rest)*)?
+                        }
+                    );
+
+                    (
+                        @ඞkeys [$($acc:tt)*] $Key:tt = $T:ty $(, $($rest:tt)*)?
+                    ) => (
+                        #macro_self_path! {
+                            @ඞkeys [$($acc)* $Key [$Key = $T],] $($($rest)*)?
+                        }
+                    );
+
+                    // Done, or unexpected input, for the `dyn` type to report.
+                    (
+                        @ඞkeys [$($acc:tt)*] $($rest:tt)*
+                    ) => (
+                        #macro_self_path! {
+                            @ඞmunch [] [] #([#SlotName])* $($acc)* $($rest)*
+                        }
+                    );
+
+                    (
+                        $($named_generics:tt)*
+                    ) => (
+                        #macro_self_path! {
+                            @ඞkeys [] $($named_generics)*
+                        }
+                    );
+                ),
+            _ => bundle_keys_arm(helper("bundle_keys")),
+        };
+
+        let several_bases_error = format!("`{TraitName}![…]`: at most one `..Base` is allowed");
+        let head = quote!(
+            (
+                @ඞmunch $base:tt [$($extra:tt)*] #($#slot:tt)*
+            ) => (
+                #macro_self_path! {
+                    @ඞfinish $base [$($extra)*] #($#slot)*
+                }
+            );
+
+            (
+                @ඞmunch [] $extra:tt #($#slot:tt)*
+                .. $Base:ty $(, $($rest:tt)*)?
+            ) => (
+                #macro_self_path! {
+                    @ඞmunch [$Base] $extra #($#slot)*
+                    $($($rest)*)?
+                }
+            );
+
+            (
+                @ඞmunch [$($prev_base:tt)+] $extra:tt #($#slot:tt)*
+                .. $($rest:tt)*
+            ) => (
+                ::core::compile_error! { #several_bases_error }
+            );
+        );
+
+        let mut parts = vec![cfg_chain::Part::Always(head)];
+        let mut key_arms = quote!();
+        for (i, (ty, args)) in body.iter().zip(field_args).enumerate() {
+            let flag = optional.iter().find(|&&(j, _, _)| j == i).map(|(_, k, Flag)| (*k, Flag));
+            let keys = ::core::iter::once(&ty.ident).chain(&args.aliases).collect::<Vec<_>>();
+            let set_flag = flag.map(|(k, _)| (k, Present.clone()));
+            key_arms.extend(keys.iter().map(|key| key_arm(key, Some(i), set_flag.clone())));
+            // The (hidden) key of the flag itself, so that the builder may set it.
+            key_arms.extend(flag.map(|(k, Flag)| key_arm(Flag, None, Some((k, quote!($($T)+))))));
+            if let Some(cfg_predicate) = &args.cfg_predicate {
+                // Keys of cfg-ed out fields are tolerated, and discarded; and so is their slot
+                // (and that of their flag).
+                let DiscardedSlot @ _ = ::core::iter::once(&ty.ident).chain(flag.map(|(_, it)| it));
+                let discarding_arms =
+                    keys.iter().copied().chain(flag.map(|(_, it)| it)).map(|key| {
+                        key_arm(key, None, None)
+                    })
+                ;
+                parts.push(cfg_chain::Part::Gated {
+                    cfg_predicate: cfg_predicate.clone(),
+                    present: quote!(),
+                    absent: quote!(
+                        #(#discarding_arms)*
+
+                        #(
+                            (
+                                @ඞfinish $base:tt [$($acc:tt)*] $({$($set:tt)*})*
+                                [#DiscardedSlot]
+                                $($slots:tt)*
+                            ) => (
+                                #macro_self_path! {
+                                    @ඞfinish $base [$($acc)* $($($set)*)*]
+                                    $($slots)*
+                                }
+                            );
+                        )*
+                    ),
+                });
+            }
+        }
+
+        // The arms emitting the `dyn` type.
+        let dyn_arms = |dyn_module: Option<&Ident>, unit_arg: TokenStream2| {
+            let dyn_module = dyn_module.map(|it| quote!(#it::));
+            quote!(
+                // Unexpected input: let the `dyn` type report it.
+                (
+                    @ඞmunch $base:tt $extra:tt #($#slot:tt)*
+                    $($rest:tt)+
+                ) => (
+                    ::core::marker::PhantomData::<fn(()) -> dyn #(#mb_module_path)* #dyn_module #TraitName<
+                        #unit_arg
+                        $($rest)+
+                    >>
+                );
+
+                // Every (remaining) slot has been set.
+                (
+                    @ඞfinish $base:tt [$($acc:tt)*] $({$($set:tt)*})*
+                ) => (
+                    ::core::marker::PhantomData::<fn(()) -> dyn #(#mb_module_path)* #dyn_module #TraitName<
+                        #unit_arg
+                        $($acc)*
+                        $($($set)*)*
+                    >>
+                );
+            )
+        };
+
+        parts.push(cfg_chain::Part::Always(quote!(
+            #key_arms
+
+            // Unknown key: let the `dyn` type report it.
+            (
+                @ඞmunch $base:tt [$($extra:tt)*] #($#slot:tt)*
+                $Key:ident [$key:tt = $($T:tt)+] $(, $($rest:tt)*)?
+            ) => (
+                #macro_self_path! {
+                    @ඞmunch $base [$($extra)* $key = $($T)+,] #($#slot)*
+                    $($($rest)*)?
+                }
+            );
+
+        )));
+
+        parts.push(if let Some(doc_dyn_module) = doc_dyn_module {
+            cfg_chain::Part::Gated {
+                cfg_predicate: quote!(doc),
+                present: dyn_arms(Some(doc_dyn_module), quote!()),
+                absent: dyn_arms(None, quote!((),)),
+            }
+        } else {
+            cfg_chain::Part::Always(dyn_arms(None, quote!((),)))
+        });
+
+        parts.push(cfg_chain::Part::Always(quote!(
+            // The finishing arms below skip over the set slots up to the next unset one, so
+            // that only the latter cost a recursion step.
+
+            // Unset optional field, and no `..Base`: `Absent`.
+            #(
+                (
+                    @ඞfinish [] [$($acc:tt)*] $({$($set:tt)*})* [#OptionalFieldName]
+                    $($slots:tt)*
+                ) => (
+                    #macro_self_path! {
+                        @ඞfinish [] [
+                            $($acc)* $($($set)*)*
+                            #OptionalFieldName = #Absent,
+                        ]
+                        $($slots)*
+                    }
+                );
+
+                (
+                    @ඞfinish [] [$($acc:tt)*] $({$($set:tt)*})* [#OptionalFlag]
+                    $($slots:tt)*
+                ) => (
+                    #macro_self_path! {
+                        @ඞfinish [] [
+                            $($acc)* $($($set)*)*
+                            #OptionalFlag = #NotPresent,
+                        ]
+                        $($slots)*
+                    }
+                );
+            )*
+
+            // Unset field: pick it from the `..Base`, if any.
+            (
+                @ඞfinish [$($Base:ty)?] [$($acc:tt)*] $({$($set:tt)*})* [$Field:ident]
+                $($slots:tt)*
+            ) => (
+                #macro_self_path! {
+                    @ඞfinish [$($Base)?] [
+                        $($acc)* $($($set)*)*
+                        $(
+                            $Field = <$Base as #(#mb_module_path)* #TraitName>::$Field,
+                        )?
+                    ]
+                    $($slots)*
+                }
+            );
+
+            // `preset`s.
+            (
+                $(#[$attr:meta])*
+                $pub:vis preset $Preset:ident = $($named_generics:tt)*
+            ) => (
+                $(#[$attr])*
+                $pub type $Preset = #macro_self_path![$($named_generics)*];
+            );
+
+            // Hand the (`#[cfg]`-annotated) fields, and the optional ones, over to some other
+            // macro.
+            (
+                @ඞwith_fields [$($callback:tt)*] $($args:tt)*
+            ) => (
+                $($callback)*! {
+                    $($args)*
+                    #fields_info
+                }
+            );
+
+            // `#[impl_bundle(…)]`.
+            (
+                @ඞimpl_bundle $($args:tt)*
+            ) => (
+                #krate::ඞ::impl_bundle_with_fields! {
+                    [#krate]
+                    $($args)*
+                    #fields_info
+                }
+            );
+
+            #bundle_keys
+        )));
+
+        let macro_rules = cfg_chain::assemble(&format!("{TraitName}ඞmacro"), parts, |arms| quote!(
+            #macro_attrs
+            macro_rules! #ඞTraitName {
+                #arms
+            }
+        ));
+        quote!(
+            #helpers_reexports
+            #macro_rules
+        )
+    }
+}
+
+/// `[#callback] [#prefix] #named_generics`: hands `#prefix #named_generics` back to
+/// `#callback!`, with every `Key = Type` turned into `Key [Key = Type]`, so that the arms
+/// matching a given `Key` literally may still get hold of the user's tokens (and their spans,
+/// for the diagnostics about the binding), without costing a recursion step per key.
+///
+/// Unexpected input is handed back as is, for the eponymous macro to report it.
+pub(crate)
+fn bundle_keys_impl(input: TokenStream2) -> Result<TokenStream2> {
+    let (callback, prefix, named_generics): (TokenStream2, TokenStream2, TokenStream2) =
+        Parser::parse2(
+            |input: ParseStream<'_>| {
+                let (callback, prefix);
+                bracketed!(callback in input);
+                bracketed!(prefix in input);
+                Ok((callback.parse()?, prefix.parse()?, input.parse()?))
+            },
+            input,
+        )?
+    ;
+    let bracket_keys = |input: ParseStream<'_>| {
+        let mut ret = quote!();
+        while input.is_empty().not() {
+            if input.peek(Token![..]) {
+                let dot2: Token![..] = input.parse()?;
+                let Base: Type = input.parse()?;
+                ret.extend(quote!(#dot2 #Base));
+            } else {
+                let Key: Ident = input.parse()?;
+                let eq_: Token![=] = input.parse()?;
+                let T: Type = input.parse()?;
+                ret.extend(quote!(#Key [#Key #eq_ #T]));
+            }
+            if input.is_empty().not() {
+                let comma: Token![,] = input.parse()?;
+                ret.extend(quote!(#comma));
+            }
+        }
+        Ok(ret)
+    };
+    let named_generics = Parser::parse2(bracket_keys, named_generics.clone())
+        .unwrap_or(named_generics)
+    ;
+    Ok(quote!(
+        #callback! {
+            #prefix
+            #named_generics
+        }
+    ))
+}
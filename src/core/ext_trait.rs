@@ -0,0 +1,73 @@
+//! The `BundleExt` extension trait, blanket-implemented for every bundle, for the items which
+//! would otherwise make the bundle trait `dyn`-incompatible (such as `const`s), or not be
+//! allowed in it (provided `fn`s and `const`s, since the eponymous macro does not implement
+//! them).
+
+use super::*;
+
+/// `None` when there is nothing to put in it.
+pub(crate)
+fn ext_trait(
+    krate: &TokenStream2,
+    pub_: &Visibility,
+    TraitName @ _: &Ident,
+    body: &Punctuated<TraitItemType, parse::Nothing>,
+    field_args: &[field_args::FieldArgs],
+    provided: &[TraitItem],
+) -> Option<TokenStream2>
+{
+    let mut items = quote!(#(#provided)*);
+    for (ty, args) in body.iter().zip(field_args) {
+        if args.optional.is_none() {
+            continue;
+        }
+        let FieldName @ _ = &ty.ident;
+        let cfg_attrs = &args.cfg_attrs;
+        let Flag @ _ = presence_flag(FieldName);
+        let field_name @ _ = &snake_case(FieldName);
+        let HAS_FIELD @ _ = format_ident!(
+            "HAS_{}",
+            field_name.to_string().trim_start_matches("r#").to_uppercase(),
+        );
+        let has_doc = format!(
+            " Whether the optional `{FieldName}` field has been set (rather than left `Absent`).",
+        );
+        let get_doc = format!(" `Some(…)` iff the optional `{FieldName}` field has been set.");
+        items.extend(quote!(
+            #(#cfg_attrs)*
+            #[doc = #has_doc]
+            const #HAS_FIELD: bool = <Self::#Flag as #krate::ඞ::Presence>::PRESENT;
+
+            #(#cfg_attrs)*
+            #[doc = #get_doc]
+            fn #field_name(
+                #field_name: &Self::#FieldName,
+            ) -> #krate::ඞ::core::option::Option<&Self::#FieldName>
+            {
+                if Self::#HAS_FIELD {
+                    #krate::ඞ::core::option::Option::Some(#field_name)
+                } else {
+                    #krate::ඞ::core::option::Option::None
+                }
+            }
+        ));
+    }
+    if items.is_empty() {
+        return None;
+    }
+
+    let TraitNameExt @ _ = format_ident!("{TraitName}Ext");
+    let doc = format!(
+        " Provided and derived items of [`{TraitName}`](trait@{TraitName}) bundles, implemented \
+        for all of them (use it to bring these items in scope).",
+    );
+    Some(quote!(
+        #[doc = #doc]
+        #pub_
+        trait #TraitNameExt : #TraitName {
+            #items
+        }
+
+        impl<ඞP : ?::core::marker::Sized + #TraitName> #TraitNameExt for ඞP {}
+    ))
+}
@@ -0,0 +1,106 @@
+//! `#[bundle(…)]` attributes on the fields (associated types) of the trait.
+
+use super::*;
+
+#[derive(Default)]
+pub(crate) struct FieldArgs {
+    /// Extra keys which the eponymous macro accepts for this field.
+    pub(crate) aliases: Vec<Ident>,
+    /// The `#[cfg]` and `#[cfg_attr]` attributes of the field, to be forwarded to every per-field
+    /// generated item.
+    pub(crate) cfg_attrs: Vec<Attribute>,
+    /// `all(…)` of the `#[cfg(…)]` predicates of the field, if any.
+    pub(crate) cfg_predicate: Option<TokenStream2>,
+    /// Whether the builder is to set this field through a type (rather than a value).
+    pub(crate) type_only: Option<kw::type_only>,
+    /// Whether the key may be omitted, the field then being `Absent`.
+    pub(crate) optional: Option<kw::optional>,
+}
+
+mod kw {
+    ::syn::custom_keyword!(alias);
+    ::syn::custom_keyword!(type_only);
+    ::syn::custom_keyword!(optional);
+}
+
+impl Parse for FieldArgs {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut ret = Self::default();
+        while input.is_empty().not() {
+            let snoopy = input.lookahead1();
+            match () {
+                _case if snoopy.peek(kw::alias) => {
+                    let _: kw::alias = input.parse().unwrap();
+                    let _: Token![=] = input.parse()?;
+                    ret.aliases.push(input.parse()?);
+                },
+                _case if snoopy.peek(kw::type_only) => {
+                    if ret.type_only.is_some() {
+                        return Err(input.error("duplicate entry"));
+                    }
+                    ret.type_only = Some(input.parse()?);
+                },
+                _case if snoopy.peek(kw::optional) => {
+                    if ret.optional.is_some() {
+                        return Err(input.error("duplicate entry"));
+                    }
+                    ret.optional = Some(input.parse()?);
+                },
+                _default => return Err(snoopy.error()),
+            }
+            let _: Option<Token![,]> = input.parse()?;
+        }
+        Ok(ret)
+    }
+}
+
+impl FieldArgs {
+    fn extend(&mut self, other: Self) {
+        let Self { aliases, cfg_attrs, cfg_predicate: _, type_only, optional } = other;
+        self.aliases.extend(aliases);
+        self.cfg_attrs.extend(cfg_attrs);
+        self.type_only = self.type_only.take().or(type_only);
+        self.optional = self.optional.take().or(optional);
+    }
+}
+
+/// Strip the `#[bundle(…)]` attributes off each field, returning their parsed contents.
+pub(crate)
+fn extract(body: &mut Punctuated<TraitItemType, parse::Nothing>) -> Result<Vec<FieldArgs>> {
+    let mut ret = vec![];
+    for ty in body.iter_mut() {
+        let mut args = FieldArgs::default();
+        let mut cfg_predicates = vec![];
+        for attr in mem::take(&mut ty.attrs) {
+            if attr.path().is_ident("bundle") {
+                args.extend(attr.parse_args()?);
+                continue;
+            }
+            if attr.path().is_ident("cfg") {
+                cfg_predicates.push(attr.meta.require_list()?.tokens.clone());
+            }
+            if attr.path().is_ident("cfg") || attr.path().is_ident("cfg_attr") {
+                args.cfg_attrs.push(attr.clone());
+            }
+            ty.attrs.push(attr);
+        }
+        if cfg_predicates.is_empty().not() {
+            args.cfg_predicate = Some(quote!(all(#(#cfg_predicates),*)));
+        }
+        ret.push(args);
+    }
+    // Sanity check: every key ought to be unambiguous.
+    let mut keys = ::std::collections::HashMap::<String, &Ident>::new();
+    let all_keys =
+        body.iter().map(|ty| &ty.ident)
+            .chain(ret.iter().flat_map(|args| &args.aliases))
+    ;
+    for key in all_keys {
+        if let Some(prev) = keys.insert(key.to_string(), key) {
+            let mut err = Error::new_spanned(key, format_args!("duplicate key `{key}`"));
+            err.combine(Error::new_spanned(prev, "previously used here"));
+            return Err(err);
+        }
+    }
+    Ok(ret)
+}
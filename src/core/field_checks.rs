@@ -0,0 +1,195 @@
+//! Per-field bound checks, so that `Bundle![Field = Wrong]` errors mention the field.
+//!
+//! The trick is to have the blanket impl require `<ඞDyn as Trait<()>>::Field : ඞFieldCheck<ඞDyn>`
+//! *before* `ඞDyn : Trait<()>`, with `ඞFieldCheck` being a `#[diagnostic::on_unimplemented]`
+//! helper trait having the field bounds as supertraits.
+//!
+//! When the field bounds do not involve `Self`, that helper trait is rather a non-generic
+//! `ඞField` one. And when the trait is known to live in an actual module
+//! (`path_to_this_very_module`), it is rather a `Field` one, in a hidden `ඞTraitඞfields` module
+//! (so that diagnostics may name it as such), alongside a `ඞField<T : Field>` trait with an
+//! identity `ItSelf` projection: the eponymous macro then binds
+//! `Field = <() as ඞField<$T>>::ItSelf`, so that the error is spanned on the user's `$T` (the
+//! blanket impl one being then deduplicated by rustc).
+
+use super::*;
+
+pub(crate) struct FieldChecks {
+    /// To be emitted within the anonymous `const` of the blanket impl.
+    pub(crate) defs: TokenStream2,
+    /// To be emitted alongside the trait.
+    pub(crate) module_defs: TokenStream2,
+    /// To be assembled with [`cfg_chain::assemble()`].
+    pub(crate) where_clauses: Vec<cfg_chain::Part>,
+    /// The hidden `ඞTraitඞfields` module.
+    pub(crate) fields_module: Ident,
+    /// Whether the given field has a `ඞField<$T>` check in that module.
+    pub(crate) type_checked: Vec<bool>,
+}
+
+pub(crate)
+fn field_checks(
+    pub_: &Visibility,
+    TraitName @ _: &Ident,
+    // Whether `path_to_this_very_module` was provided, so that `super::` may be used.
+    in_a_module: bool,
+    body: &Punctuated<TraitItemType, parse::Nothing>,
+    field_args: &[field_args::FieldArgs],
+) -> FieldChecks
+{
+    let fields_module = format_ident!("ඞ{TraitName}ඞfields");
+    let mut defs = quote!();
+    let mut module_defs = quote!();
+    let mut fields_module_items = quote!();
+    let mut where_clauses = vec![];
+    let mut type_checked = vec![];
+    for (ty, args) in body.iter().zip(field_args) {
+        let FieldName @ _ = &ty.ident;
+        let cfg_attrs = &args.cfg_attrs;
+        let bounds = ty.bounds.iter().filter(|bound| matches!(
+            bound,
+            TypeParamBound::Trait(TraitBound { modifier: TraitBoundModifier::Maybe(_), .. }),
+        ).not()).collect::<Vec<_>>();
+        type_checked.push(false);
+        if bounds.is_empty()
+        || ty.generics.params.is_empty().not()
+        || bounds.iter().any(|bound| mentions_non_projection_self(bound.to_token_stream()))
+        {
+            continue;
+        }
+        // `Self::Other` projections.
+        let mentions_self = bounds.iter().any(|bound| mentions_self(bound.to_token_stream()));
+        let bounds_str = bounds.iter().map(pretty_tokens).collect::<Vec<_>>().join(" + ");
+        let message = format!(
+            "`{{Self}}` cannot be used as `{FieldName}` in `{TraitName}![…]`: \
+            it does not implement `{bounds_str}`",
+        );
+        let label = format!("`{FieldName} = {{Self}}` does not implement `{bounds_str}`");
+        let where_clause = if mentions_self {
+            let ඞFieldCheck @ _ = format_ident!("ඞ{FieldName}Check");
+            let Bound @ _ = bounds.iter().map(|bound| replace_self(bound.to_token_stream()));
+            let Bound2 @ _ = Bound.clone();
+            defs.extend(quote!(
+                #(#cfg_attrs)*
+                #[diagnostic::on_unimplemented(
+                    message = #message,
+                    label = #label,
+                )]
+                trait #ඞFieldCheck<ඞBundle : ?::core::marker::Sized + #TraitName<()>>
+                :
+                    #(#Bound +)*
+                {}
+
+                #(#cfg_attrs)*
+                #[diagnostic::do_not_recommend]
+                impl<ඞT, ඞBundle>
+                    #ඞFieldCheck<ඞBundle>
+                for
+                    ඞT
+                where
+                    ඞT : ?::core::marker::Sized #(+ #Bound2)*,
+                    ඞBundle : ?::core::marker::Sized + #TraitName<()>,
+                {}
+            ));
+            quote!(
+                <ඞDyn as #TraitName<()>>::#FieldName : #ඞFieldCheck<ඞDyn>,
+            )
+        } else if in_a_module.not() {
+            let ඞField @ _ = format_ident!("ඞ{FieldName}");
+            defs.extend(quote!(
+                #(#cfg_attrs)*
+                #[diagnostic::on_unimplemented(
+                    message = #message,
+                    label = #label,
+                )]
+                trait #ඞField : #(#bounds +)* {}
+
+                #(#cfg_attrs)*
+                #[diagnostic::do_not_recommend]
+                impl<ඞT : ?::core::marker::Sized #(+ #bounds)*> #ඞField for ඞT {}
+            ));
+            quote!(
+                <ඞDyn as #TraitName<()>>::#FieldName : #ඞField,
+            )
+        } else {
+            // The bounds are spelled out at the level of the trait, since they are to be
+            // resolved from there; the `ඞTraitඞfields` module only refers to them.
+            let ඞTraitඞField @ _ = format_ident!("ඞ{TraitName}ඞ{FieldName}");
+            let ඞField @ _ = format_ident!("ඞ{FieldName}");
+            let Sized @ _ = quote!(::core::marker::Sized);
+            module_defs.extend(quote!(
+                #(#cfg_attrs)*
+                #[doc(hidden)]
+                #pub_ trait #ඞTraitඞField : #(#bounds +)* {}
+
+                #(#cfg_attrs)*
+                impl<ඞT : ?::core::marker::Sized #(+ #bounds)*> #ඞTraitඞField for ඞT {}
+            ));
+            *type_checked.last_mut().unwrap() = true;
+            fields_module_items.extend(quote!(
+                #(#cfg_attrs)*
+                #[diagnostic::on_unimplemented(
+                    message = #message,
+                    label = #label,
+                )]
+                pub trait #FieldName : super::#ඞTraitඞField {}
+
+                #(#cfg_attrs)*
+                #[diagnostic::do_not_recommend]
+                impl<ඞT : ?#Sized + super::#ඞTraitඞField> #FieldName for ඞT {}
+
+                #(#cfg_attrs)*
+                pub trait #ඞField<ඞT : ?#Sized + #FieldName> {
+                    type ItSelf : ?#Sized;
+                }
+
+                #(#cfg_attrs)*
+                impl<ඞT : ?#Sized + #FieldName> #ඞField<ඞT> for () {
+                    type ItSelf = ඞT;
+                }
+            ));
+            quote!(
+                <ඞDyn as #TraitName<()>>::#FieldName : #fields_module::#FieldName,
+            )
+        };
+        where_clauses.push(match &args.cfg_predicate {
+            None => cfg_chain::Part::Always(where_clause),
+            Some(cfg_predicate) => cfg_chain::Part::Gated {
+                cfg_predicate: cfg_predicate.clone(),
+                present: where_clause,
+                absent: quote!(),
+            },
+        });
+    }
+    if fields_module_items.is_empty().not() {
+        module_defs.extend(quote!(
+            #[doc(hidden)]
+            #[allow(nonstandard_style)]
+            #pub_ mod #fields_module {
+                #fields_module_items
+            }
+        ));
+    }
+    FieldChecks { defs, module_defs, where_clauses, fields_module, type_checked }
+}
+
+fn mentions_self(ts: TokenStream2) -> bool {
+    ts.into_iter().any(|tt| match tt {
+        TT::Ident(ident) => ident == "Self",
+        TT::Group(g) => mentions_self(g.stream()),
+        _ => false,
+    })
+}
+
+/// `Self::Field` ~> `ඞBundle::Field`.
+fn replace_self(ts: TokenStream2) -> TokenStream2 {
+    ts.into_iter().map(|tt| match tt {
+        TT::Ident(ident) if ident == "Self" => Ident::new("ඞBundle", ident.span()).into(),
+        TT::Group(g) => {
+            let mut new = Group::new(g.delimiter(), replace_self(g.stream()));
+            new.set_span(g.span());
+            new.into()
+        },
+        _ => tt,
+    }).collect()
+}
@@ -0,0 +1,189 @@
+//! `#[impl_bundle(Bundle![Field = Type, …])] struct Marker;`
+//!
+//! The `impl` itself is emitted once the eponymous macro has called us back with the fields
+//! of the bundle (so as to default the omitted optional ones):
+//! `[#krate] [#TraitPath] [#Marker] [#bindings] [#TraitPath] [#fields] [#optional_fields]`.
+
+use super::*;
+
+/// `Bundle![Field = Type, …]`
+struct ImplBundleArgs {
+    TraitPath: Path,
+    bindings: Punctuated<Binding, Token![,]>,
+}
+
+struct Binding {
+    attrs: Vec<Attribute>,
+    FieldName: Ident,
+    eq_: Token![=],
+    Type: Type,
+}
+
+impl Parse for Binding {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        Ok(Self {
+            attrs: Attribute::parse_outer(input)?,
+            FieldName: input.parse()?,
+            eq_: input.parse()?,
+            Type: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Binding {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let Self { attrs, FieldName, eq_, Type } = self;
+        tokens.extend(quote!(#(#attrs)* #FieldName #eq_ #Type));
+    }
+}
+
+impl Parse for ImplBundleArgs {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let TraitPath = Path::parse_mod_style(input)?;
+        let _: Token![!] = input.parse()?;
+        let contents = input.step(|cursor| match cursor.token_tree() {
+            Some((TT::Group(g), rest)) if g.delimiter() != Delimiter::None => {
+                Ok((g.stream(), rest))
+            },
+            _ => Err(cursor.error("expected `[…]`")),
+        })?;
+        let _: Option<Token![,]> = input.parse()?;
+        Ok(Self {
+            TraitPath,
+            bindings: Parser::parse2(Punctuated::parse_terminated, contents)?,
+        })
+    }
+}
+
+pub(crate)
+fn impl_bundle_impl(
+    args: TokenStream2,
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    let ImplBundleArgs { TraitPath, bindings } = parse2(args)?;
+    let ItemStruct { ref ident, ref generics, ref fields, .. } = parse2(input.clone())?;
+    if matches!(fields, Fields::Unit).not() || generics.params.is_empty().not() {
+        return Err(Error::new_spanned(
+            ident,
+            format_args!("expected a (non-generic) unit struct, such as `struct {ident};`"),
+        ));
+    }
+    let std_derives = std_derives();
+    Ok(quote!(
+        #std_derives
+        #input
+
+        #TraitPath! {
+            @ඞimpl_bundle [#TraitPath] [#ident] [#bindings]
+        }
+    ))
+}
+
+/// `#(#cfg_attrs)* #FieldName`, as provided by the eponymous macro.
+struct Field {
+    attrs: Vec<Attribute>,
+    FieldName: Ident,
+}
+
+impl Parse for Field {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        Ok(Self {
+            attrs: Attribute::parse_outer(input)?,
+            FieldName: input.parse()?,
+        })
+    }
+}
+
+struct Callback {
+    krate: TokenStream2,
+    TraitPath: Path,
+    Marker: Ident,
+    bindings: Punctuated<Binding, Token![,]>,
+    fields: Vec<Field>,
+    optional: Vec<Ident>,
+}
+
+impl Parse for Callback {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let (krate, trait_path, marker, bindings, full_trait_path, fields, optional);
+        bracketed!(krate in input);
+        bracketed!(trait_path in input);
+        bracketed!(marker in input);
+        bracketed!(bindings in input);
+        // The user-provided path is the one to be used, here.
+        bracketed!(full_trait_path in input);
+        let _: TokenStream2 = full_trait_path.parse()?;
+        bracketed!(fields in input);
+        bracketed!(optional in input);
+        fn many<T : Parse>(input: ParseStream<'_>) -> Result<Vec<T>> {
+            let mut ret = vec![];
+            while input.is_empty().not() {
+                ret.push(input.parse()?);
+            }
+            Ok(ret)
+        }
+        Ok(Self {
+            krate: krate.parse()?,
+            TraitPath: Path::parse_mod_style(&trait_path)?,
+            Marker: marker.parse()?,
+            bindings: Punctuated::parse_terminated(&bindings)?,
+            fields: many(&fields)?,
+            optional: many(&optional)?,
+        })
+    }
+}
+
+pub(crate)
+fn impl_bundle_with_fields_impl(input: TokenStream2) -> Result<TokenStream2> {
+    let Callback { krate, TraitPath, Marker, bindings, fields, optional } = parse2(input)?;
+    let Binding @ _ = bindings.iter().map(|Binding { attrs, FieldName, eq_, Type }| quote!(
+        #(#attrs)*
+        type #FieldName #eq_ #Type;
+    ));
+    // The presence flags of the optional fields, and the `Absent` ones.
+    let mut optional_items = quote!();
+    for Field { attrs, FieldName } in &fields {
+        if optional.contains(FieldName).not() {
+            continue;
+        }
+        let Flag @ _ = presence_flag(FieldName);
+        optional_items.extend(
+            if bindings.iter().any(|binding| binding.FieldName == *FieldName) {
+                // Any explicit type, `Absent` included, counts as present.
+                quote!(
+                    #(#attrs)*
+                    type #Flag = #krate::ඞ::Present;
+                )
+            } else {
+                quote!(
+                    #(#attrs)*
+                    type #FieldName = #krate::ඞ::Absent;
+                    #(#attrs)*
+                    type #Flag = #krate::ඞ::NotPresent;
+                )
+            }
+        );
+    }
+    Ok(quote!(
+        impl #TraitPath for #Marker {
+            #(#Binding)*
+            #optional_items
+        }
+    ))
+}
+
+/// So as to satisfy the implied bounds of the bundle, whichever they may be.
+pub(crate)
+fn std_derives() -> TokenStream2 {
+    quote!(
+        #[derive(
+            ::core::fmt::Debug,
+            ::core::clone::Clone, ::core::marker::Copy,
+            ::core::cmp::PartialEq, ::core::cmp::Eq,
+            ::core::cmp::PartialOrd, ::core::cmp::Ord,
+            ::core::hash::Hash,
+            ::core::default::Default,
+        )]
+    )
+}
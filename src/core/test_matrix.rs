@@ -0,0 +1,219 @@
+//! `bundle_test_matrix!(Bundle { Field: [Type, …], … } => fn check<P>() { … })`
+//!
+//! The facade `macro_rules!` hands the invocation over to the eponymous macro of the bundle,
+//! which calls us back with its fields appended, so as to check their coverage:
+//! `[#matrix] [#TraitPath] [#( #(#cfg_attrs)* #FieldName )*] [#optional_fields]`.
+
+use super::*;
+
+/// `Bundle { Field: [Type, …], … } => fn check<P>() { … }`
+struct TestMatrix {
+    BundlePath: Path,
+    rows: Punctuated<Row, Token![,]>,
+    test_fn: ItemFn,
+}
+
+/// `Field: [Type, …]`
+struct Row {
+    FieldName: Ident,
+    brackets: token::Bracket,
+    candidates: Punctuated<Type, Token![,]>,
+}
+
+impl Parse for Row {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let contents;
+        Ok(Self {
+            FieldName: input.parse()?,
+            brackets: {
+                let _: Token![:] = input.parse()?;
+                bracketed!(contents in input)
+            },
+            candidates: Punctuated::parse_terminated(&contents)?,
+        })
+    }
+}
+
+impl Parse for TestMatrix {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let contents;
+        Ok(Self {
+            BundlePath: Path::parse_mod_style(input)?,
+            rows: {
+                braced!(contents in input);
+                Punctuated::parse_terminated(&contents)?
+            },
+            test_fn: {
+                let _: Token![=>] = input.parse()?;
+                input.parse()?
+            },
+        })
+    }
+}
+
+/// `#(#cfg_attrs)* #FieldName`, as provided by the eponymous macro.
+struct Field {
+    attrs: Vec<Attribute>,
+    FieldName: Ident,
+}
+
+impl Parse for Field {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        Ok(Self {
+            attrs: Attribute::parse_outer(input)?,
+            FieldName: input.parse()?,
+        })
+    }
+}
+
+struct Callback {
+    matrix: TestMatrix,
+    TraitPath: TokenStream2,
+    fields: Vec<Field>,
+    optional: Vec<Ident>,
+}
+
+impl Parse for Callback {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let (matrix, trait_path, fields, optional);
+        bracketed!(matrix in input);
+        bracketed!(trait_path in input);
+        bracketed!(fields in input);
+        bracketed!(optional in input);
+        Ok(Self {
+            matrix: matrix.parse()?,
+            TraitPath: trait_path.parse()?,
+            fields: {
+                let mut ret = vec![];
+                while fields.is_empty().not() {
+                    ret.push(fields.parse()?);
+                }
+                ret
+            },
+            optional: {
+                let mut ret = vec![];
+                while optional.is_empty().not() {
+                    ret.push(optional.parse()?);
+                }
+                ret
+            },
+        })
+    }
+}
+
+pub(crate)
+fn bundle_test_matrix_impl(input: TokenStream2) -> Result<TokenStream2> {
+    let Callback { matrix, TraitPath, fields, optional } = parse2(input)?;
+    let TestMatrix { BundlePath, rows, mut test_fn } = matrix;
+    let TraitName = pretty_tokens(&BundlePath);
+
+    // Check the coverage of the fields.
+    let mut errors = vec![];
+    for (i, row) in rows.iter().enumerate() {
+        let FieldName = &row.FieldName;
+        if fields.iter().all(|field| field.FieldName != *FieldName) {
+            errors.push(Error::new_spanned(
+                FieldName,
+                format_args!("`{FieldName}` is not a field of `{TraitName}`"),
+            ));
+        } else if rows.iter().take(i).any(|prev| prev.FieldName == *FieldName) {
+            errors.push(Error::new_spanned(FieldName, "duplicate field"));
+        }
+        if row.candidates.is_empty() {
+            errors.push(Error::new(
+                row.brackets.span.join(),
+                format_args!("expected at least one candidate for `{FieldName}`"),
+            ));
+        }
+    }
+    // `#[cfg]`-dependent fields are left to the eponymous macro, and optional ones may be left
+    // `Absent`.
+    let missing =
+        fields
+            .iter()
+            .filter(|field| field.attrs.iter().any(|attr| attr.path().is_ident("cfg")).not())
+            .filter(|field| optional.contains(&field.FieldName).not())
+            .filter(|field| rows.iter().all(|row| row.FieldName != field.FieldName))
+            .map(|field| format!("`{}`", field.FieldName))
+            .collect::<Vec<_>>()
+    ;
+    if missing.is_empty().not() {
+        errors.push(Error::new_spanned(
+            &BundlePath,
+            format_args!(
+                "missing candidates for {}: every field of `{TraitName}` is to be covered",
+                missing.join(", "),
+            ),
+        ));
+    }
+
+    // Check the shape of the test function, bounding its type parameter by the bundle trait.
+    let fn_name = &test_fn.sig.ident.clone();
+    let mut type_params = test_fn.sig.generics.type_params_mut();
+    match (type_params.next(), type_params.next()) {
+        (Some(P), None) => P.bounds.push(parse_quote!(#TraitPath)),
+        _ => errors.push(Error::new_spanned(
+            &test_fn.sig.generics,
+            format_args!("expected exactly one type parameter, such as `fn {fn_name}<P>()`"),
+        )),
+    }
+    if test_fn.sig.inputs.is_empty().not() {
+        errors.push(Error::new_spanned(&test_fn.sig.inputs, "expected no parameters"));
+    }
+    let mut errors = errors.into_iter();
+    if let Some(mut err) = errors.next() {
+        errors.for_each(|cur| err.combine(cur));
+        return Err(err);
+    }
+
+    // One test per combination, named after the chosen types.
+    let mut combinations = vec![(format!("{fn_name}"), quote!())];
+    for Row { FieldName, candidates, .. } in &rows {
+        combinations = combinations.iter().flat_map(|(name, bindings)| {
+            candidates.iter().map(move |Type| (
+                format!("{name}__{FieldName}_{}", ident_friendly(Type)),
+                quote!(#bindings #FieldName = #Type,),
+            ))
+        }).collect();
+    }
+    let mut names = ::std::collections::HashSet::new();
+    let test = combinations.into_iter().map(|(name, bindings)| {
+        let mut test_name = name.clone();
+        for i in 2.. {
+            if names.insert(test_name.clone()) {
+                break;
+            }
+            test_name = format!("{name}_{i}");
+        }
+        let test_name = Ident::new(&test_name, fn_name.span());
+        let ret = &test_fn.sig.output;
+        quote!(
+            #[::core::prelude::v1::test]
+            #[allow(non_snake_case)]
+            fn #test_name() #ret {
+                #fn_name::<#BundlePath![#bindings]>()
+            }
+        )
+    });
+    Ok(quote!(
+        #test_fn
+
+        #(#test)*
+    ))
+}
+
+/// `Vec<u8>` to `Vec_u8`, and `()` to `unit`.
+fn ident_friendly(ty: &Type) -> String {
+    let mut ret = String::new();
+    for c in pretty_tokens(ty).replace("r#", "").chars() {
+        if c.is_alphabetic() || c.is_ascii_digit() || c == '_' {
+            ret.push(c);
+        } else if ret.ends_with('_').not() {
+            ret.push('_');
+        }
+    }
+    match ret.trim_matches('_') {
+        "" => "unit".into(),
+        trimmed => trimmed.into(),
+    }
+}
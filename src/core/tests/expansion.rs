@@ -0,0 +1,128 @@
+//! Snapshots of the expansions, in `tests/snapshots/`, which `SNAPSHOTS=overwrite cargo test`
+//! (re)writes.
+
+use ::named_generics_bundle_core as core_;
+use ::quote::quote;
+use ::std::{env, fs, path::Path};
+
+#[track_caller]
+fn assert_snapshot(name: &str, expansion: ::proc_macro2::TokenStream) {
+    let expansion = ::prettyplease::unparse(&::syn::parse2(expansion).unwrap());
+    let path = &Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("tests/snapshots/{name}.rs"));
+    if env::var_os("SNAPSHOTS").is_some_and(|it| it == "overwrite") {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, expansion).unwrap();
+        return;
+    }
+    let snapshot = fs::read_to_string(path).unwrap_or_else(|err| panic!(
+        "`{}`: {err} (run with `SNAPSHOTS=overwrite` to write it)", path.display(),
+    ));
+    assert_eq!(
+        snapshot.replace("\r\n", "\n"), expansion,
+        "`{}` is outdated (run with `SNAPSHOTS=overwrite` to update it)", path.display(),
+    );
+}
+
+#[test]
+fn bundle() {
+    assert_snapshot("bundle", core_::named_generics_bundle(quote!(), quote!(
+        pub trait DeviceSetup {
+            type Fuel;
+        }
+    )).unwrap());
+}
+
+#[test]
+fn bundle_with_args() {
+    assert_snapshot("bundle_with_args", core_::named_generics_bundle(
+        quote!(
+            path_to_this_very_module = crate::setup,
+            builder = DeviceSetupBuilder,
+        ),
+        quote!(
+            /// Docs.
+            pub trait DeviceSetup : 'static {
+                type Fuel : Clone;
+
+                #[bundle(optional)]
+                type Metrics;
+
+                #[cfg(feature = "turbo")]
+                type Engine;
+            }
+        ),
+    ).unwrap());
+}
+
+#[test]
+fn from_trait() {
+    let item: ::syn::ItemTrait = ::syn::parse_quote!(
+        trait DeviceSetup {
+            type Fuel;
+        }
+    );
+    assert_eq!(
+        core_::named_generics_bundle_from_trait(quote!(sealed), &item).unwrap().to_string(),
+        core_::named_generics_bundle(quote!(sealed), quote!(#item)).unwrap().to_string(),
+    );
+}
+
+#[test]
+fn impl_bundle() {
+    assert_snapshot("impl_bundle", core_::impl_bundle(
+        quote!(DeviceSetup![Fuel = Uranium]),
+        quote!(struct Prod;),
+    ).unwrap());
+
+    // As called back by the eponymous macro.
+    assert_snapshot("impl_bundle_with_fields", core_::impl_bundle_with_fields(quote!(
+        [::nmb] [DeviceSetup] [Prod] [Fuel = Uranium]
+        [crate::DeviceSetup] [Fuel #[cfg(any())] Metrics] [Metrics]
+    )).unwrap());
+}
+
+#[test]
+fn apply() {
+    assert_snapshot("apply", core_::apply(quote!(my_bundle!), quote!(
+        #[named_generics_bundle(sealed)]
+        pub trait DeviceSetup {
+            type Fuel;
+        }
+    )).unwrap());
+
+    let err = core_::apply(quote!(my_bundle), quote!(trait DeviceSetup {})).unwrap_err();
+    assert_eq!(err.to_string(), "expected `!`");
+}
+
+#[test]
+fn test_matrix() {
+    assert_snapshot("test_matrix", core_::bundle_test_matrix(quote!(
+        [DeviceSetup { Fuel: [Uranium, Vec<u8>] } => fn check<P>() {}]
+        [crate::DeviceSetup]
+        [Fuel #[cfg(any())] Engine Metrics]
+        [Metrics]
+    )).unwrap());
+
+    let err = core_::bundle_test_matrix(quote!(
+        [DeviceSetup { Fuel: [()] } => fn check<P>() {}]
+        [crate::DeviceSetup]
+        [Fuel Engine]
+        []
+    )).unwrap_err();
+    assert!(err.to_string().starts_with("missing candidates for `Engine`"), "{err}");
+}
+
+#[test]
+fn errors() {
+    let err = core_::named_generics_bundle(quote!(sealed, sealed), quote!(
+        trait DeviceSetup {}
+    )).unwrap_err();
+    assert_eq!(err.to_string(), "duplicate entry");
+
+    let err = core_::named_generics_bundle(quote!(), quote!(
+        trait DeviceSetup {
+            fn f();
+        }
+    )).unwrap_err();
+    assert!(err.to_string().starts_with("expected"), "{err}");
+}
@@ -0,0 +1,3 @@
+my_bundle! {
+    #[named_generics_bundle(sealed)] pub trait DeviceSetup { type Fuel; }
+}
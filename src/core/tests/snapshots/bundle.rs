@@ -0,0 +1,116 @@
+///
+/// Instantiated through the [`DeviceSetup![…]`](macro@DeviceSetup) macro.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a `DeviceSetup![…]` bundle",
+    label = "expected a `DeviceSetup![…]` bundle",
+    note = "the `DeviceSetup` bundle expects the fields: `Fuel`",
+    note = "build one with `DeviceSetup![Fuel = …]`",
+)]
+pub trait DeviceSetup<
+    ඞImpliedDeriveBounds = Self,
+>: ::named_generics_bundle::ඞ::ImpliedPredicate<
+        ඞImpliedDeriveBounds,
+        Impls: ::named_generics_bundle::ඞ::Debug + ::named_generics_bundle::ඞ::Copy
+            + ::named_generics_bundle::ඞ::Ord + ::named_generics_bundle::ඞ::Hash
+            + ::named_generics_bundle::ඞ::Default + ::named_generics_bundle::ඞ::Send
+            + ::named_generics_bundle::ඞ::Sync + ::named_generics_bundle::ඞ::Unpin,
+    > {
+    type Fuel;
+}
+#[allow(deprecated)]
+const _: () = {
+    impl<ඞDyn: ?::core::marker::Sized> DeviceSetup
+    for ::named_generics_bundle::ඞ::core::marker::PhantomData<
+        fn(::named_generics_bundle::ඞ::ඞ<()>) -> ඞDyn,
+    >
+    where
+        ඞDyn: DeviceSetup<()>,
+    {
+        type Fuel = ඞDyn::Fuel;
+    }
+};
+/// Instantiate a [`DeviceSetup`](trait@DeviceSetup) bundle.
+///
+/// ```rust ,ignore
+/// DeviceSetup![
+///     Fuel = /* … */,
+/// ]
+/// ```
+///
+/// | Field | Bounds | Docs |
+/// |-------|--------|------|
+/// | [`Fuel`](DeviceSetup::Fuel) |  |  |
+///
+/// The resulting type also implements `Debug + Copy + Ord + Hash + Default + Send + Sync + Unpin`, so as to be
+/// dumb-stdlib-`#[derive()]`-friendly.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __proper_macro_rules_scopingඞnamed_generics_bundleඞDeviceSetup {
+    (@ ඞmunch $base:tt [$($extra:tt)*] $slot0:tt) => {
+        DeviceSetup! { @ ඞfinish $base [$($extra)*] $slot0 }
+    };
+    (@ ඞmunch[] $extra:tt $slot0:tt .. $Base:ty $(, $($rest:tt)*)?) => {
+        DeviceSetup! { @ ඞmunch[$Base] $extra $slot0 $($($rest)*)? }
+    };
+    (@ ඞmunch[$($prev_base:tt)+] $extra:tt $slot0:tt .. $($rest:tt)*) => {
+        ::core::compile_error! { "`DeviceSetup![…]`: at most one `..Base` is allowed" }
+    };
+    (
+        @ ඞmunch $base:tt $extra:tt [$unset:ident] Fuel[$key:tt = $($T:tt)+] $(,
+        $($rest:tt)*)?
+    ) => {
+        DeviceSetup! { @ ඞmunch $base $extra { $key = $($T)+, } $($($rest)*)? }
+    };
+    (
+        @ ඞmunch $base:tt $extra:tt { $($set:tt)* } Fuel[$key:tt = $($T:tt)+] $(,
+        $($rest:tt)*)?
+    ) => {
+        DeviceSetup! { @ ඞmunch $base $extra { $($set)* $key = $($T)+, } $($($rest)*)?
+        }
+    };
+    (
+        @ ඞmunch $base:tt [$($extra:tt)*] $slot0:tt $Key:ident [$key:tt = $($T:tt)+]
+        $(, $($rest:tt)*)?
+    ) => {
+        DeviceSetup! { @ ඞmunch $base [$($extra)* $key = $($T)+,] $slot0 $($($rest)*)?
+        }
+    };
+    (@ ඞmunch $base:tt $extra:tt $slot0:tt $($rest:tt)+) => {
+        ::core::marker::PhantomData:: < fn (()) -> dyn DeviceSetup < (), $($rest)+ >>
+    };
+    (@ ඞfinish $base:tt [$($acc:tt)*] $({ $($set:tt)* })*) => {
+        ::core::marker::PhantomData:: < fn (()) -> dyn DeviceSetup < (), $($acc)*
+        $($($set)*)* >>
+    };
+    (
+        @ ඞfinish[$($Base:ty)?] [$($acc:tt)*] $({ $($set:tt)* })* [$Field:ident]
+        $($slots:tt)*
+    ) => {
+        DeviceSetup! { @ ඞfinish[$($Base)?] [$($acc)* $($($set)*)* $($Field = < $Base
+        as DeviceSetup > :: $Field,)?] $($slots)* }
+    };
+    ($(#[$attr:meta])* $pub:vis preset $Preset:ident = $($named_generics:tt)*) => {
+        $(#[$attr])* $pub type $Preset = DeviceSetup![$($named_generics)*];
+    };
+    (@ ඞwith_fields[$($callback:tt)*] $($args:tt)*) => {
+        $($callback)* ! { $($args)* [DeviceSetup] [Fuel] [] }
+    };
+    (@ ඞimpl_bundle $($args:tt)*) => {
+        ::named_generics_bundle::ඞ::impl_bundle_with_fields! {
+        [::named_generics_bundle] $($args)* [DeviceSetup] [Fuel] [] }
+    };
+    (@ ඞkeys[$($acc:tt)*] .. $Base:ty $(, $($rest:tt)*)?) => {
+        DeviceSetup! { @ ඞkeys[$($acc)* .. $Base,] $($($rest)*)? }
+    };
+    (@ ඞkeys[$($acc:tt)*] $Key:tt = $T:ty $(, $($rest:tt)*)?) => {
+        DeviceSetup! { @ ඞkeys[$($acc)* $Key [$Key = $T],] $($($rest)*)? }
+    };
+    (@ ඞkeys[$($acc:tt)*] $($rest:tt)*) => {
+        DeviceSetup! { @ ඞmunch[] [] [Fuel] $($acc)* $($rest)* }
+    };
+    ($($named_generics:tt)*) => {
+        DeviceSetup! { @ ඞkeys[] $($named_generics)* }
+    };
+}
+#[doc(inline)]
+pub use __proper_macro_rules_scopingඞnamed_generics_bundleඞDeviceSetup as DeviceSetup;
@@ -0,0 +1,390 @@
+const _: () = {
+    use ::named_generics_bundle::ඞ::{
+        core::{
+            module_path, panic, primitive::str,
+            stringify, unreachable,
+        },
+        constcat, eq_modulo_whitespace, find_subslice,
+    };
+    const MODULE_PATH: &str = {
+        const PATH: &str = find_subslice(module_path!(), b':');
+        let Ok(s) = ::core::str::from_utf8(const {
+            &constcat::<{ "crate".len() + PATH.len() }, 2>(["crate", PATH])
+        }) else {
+            unreachable!();
+        };
+        s
+    };
+    if !eq_modulo_whitespace(stringify!(crate ::setup), MODULE_PATH) {
+        const PREFIX: &str = "expected `";
+        const SUFFIX: &str = "`";
+        let Ok(msg) = ::core::str::from_utf8(const {
+            &constcat::<
+                { PREFIX.len() + MODULE_PATH.len() + SUFFIX.len() },
+                3,
+            >([PREFIX, MODULE_PATH, SUFFIX])
+        }) else {
+            unreachable!();
+        };
+        panic! {
+            "{}", msg
+        }
+    }
+};
+/// Docs.
+///
+/// Instantiated through the [`DeviceSetup![…]`](macro@DeviceSetup) macro.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a `DeviceSetup![…]` bundle",
+    label = "expected a `DeviceSetup![…]` bundle",
+    note = "the `DeviceSetup` bundle expects the fields: `Fuel`, `Metrics`, `Engine`",
+    note = "build one with `DeviceSetup![Fuel = …, Metrics = …, Engine = …]`",
+)]
+pub trait DeviceSetup<
+    ඞImpliedDeriveBounds = Self,
+>: 'static + ::named_generics_bundle::ඞ::ImpliedPredicate<
+        ඞImpliedDeriveBounds,
+        Impls: ::named_generics_bundle::ඞ::Debug + ::named_generics_bundle::ඞ::Copy
+            + ::named_generics_bundle::ඞ::Ord + ::named_generics_bundle::ඞ::Hash
+            + ::named_generics_bundle::ඞ::Default + ::named_generics_bundle::ඞ::Send
+            + ::named_generics_bundle::ඞ::Sync + ::named_generics_bundle::ඞ::Unpin,
+    > {
+    type Fuel: Clone;
+    type Metrics;
+    #[cfg(feature = "turbo")]
+    type Engine;
+    #[doc(hidden)]
+    type ඞMetricsඞpresent: ::named_generics_bundle::ඞ::Presence;
+}
+#[doc(hidden)]
+pub trait ඞDeviceSetupඞFuel: Clone {}
+impl<ඞT: ?::core::marker::Sized + Clone> ඞDeviceSetupඞFuel for ඞT {}
+#[doc(hidden)]
+#[allow(nonstandard_style)]
+pub mod ඞDeviceSetupඞfields {
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` cannot be used as `Fuel` in `DeviceSetup![…]`: it does not implement `Clone`",
+        label = "`Fuel = {Self}` does not implement `Clone`",
+    )]
+    pub trait Fuel: super::ඞDeviceSetupඞFuel {}
+    #[diagnostic::do_not_recommend]
+    impl<ඞT: ?::core::marker::Sized + super::ඞDeviceSetupඞFuel> Fuel for ඞT {}
+    pub trait ඞFuel<ඞT: ?::core::marker::Sized + Fuel> {
+        type ItSelf: ?::core::marker::Sized;
+    }
+    impl<ඞT: ?::core::marker::Sized + Fuel> ඞFuel<ඞT> for () {
+        type ItSelf = ඞT;
+    }
+}
+/// Provided and derived items of [`DeviceSetup`](trait@DeviceSetup) bundles, implemented for all of them (use it to bring these items in scope).
+pub trait DeviceSetupExt: DeviceSetup {
+    /// Whether the optional `Metrics` field has been set (rather than left `Absent`).
+    const HAS_METRICS: bool = <Self::ඞMetricsඞpresent as ::named_generics_bundle::ඞ::Presence>::PRESENT;
+    /// `Some(…)` iff the optional `Metrics` field has been set.
+    fn metrics(
+        metrics: &Self::Metrics,
+    ) -> ::named_generics_bundle::ඞ::core::option::Option<&Self::Metrics> {
+        if Self::HAS_METRICS {
+            ::named_generics_bundle::ඞ::core::option::Option::Some(metrics)
+        } else {
+            ::named_generics_bundle::ඞ::core::option::Option::None
+        }
+    }
+}
+impl<ඞP: ?::core::marker::Sized + DeviceSetup> DeviceSetupExt for ඞP {}
+#[cfg(all())]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupBuilderඞbuilderඞcfg_0 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupBuilderඞbuilderඞcfg_1! { [$($acc)* $($present)*] $($rest)* }
+    };
+}
+#[cfg(not(all()))]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupBuilderඞbuilderඞcfg_0 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupBuilderඞbuilderඞcfg_1! { [$($acc)* $($absent)*] $($rest)* }
+    };
+}
+#[cfg(all())]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupBuilderඞbuilderඞcfg_1 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupBuilderඞbuilderඞcfg_2! { [$($acc)* $($present)*] $($rest)* }
+    };
+}
+#[cfg(not(all()))]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupBuilderඞbuilderඞcfg_1 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupBuilderඞbuilderඞcfg_2! { [$($acc)* $($absent)*] $($rest)* }
+    };
+}
+#[cfg(all(feature = "turbo"))]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupBuilderඞbuilderඞcfg_2 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupBuilderඞbuilderඞcfg_3! { [$($acc)* $($present)*] $($rest)* }
+    };
+}
+#[cfg(not(all(feature = "turbo")))]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupBuilderඞbuilderඞcfg_2 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupBuilderඞbuilderඞcfg_3! { [$($acc)* $($absent)*] $($rest)* }
+    };
+}
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupBuilderඞbuilderඞcfg_3 {
+    ([$($acc:tt)*]) => {
+        ::named_generics_bundle::ඞ::bundle_builder! { [::named_generics_bundle] [pub]
+        [DeviceSetup] [DeviceSetupBuilder] [$($acc)*] }
+    };
+}
+__ඞDeviceSetupBuilderඞbuilderඞcfg_0! {
+    [] [[Fuel value checked]] [[Fuel value checked]] [[Metrics value optional]] [[Metrics
+    value optional]] [[Engine value]] []
+}
+#[allow(deprecated)]
+const _: () = {
+    impl<ඞDyn: ?::core::marker::Sized> DeviceSetup
+    for ::named_generics_bundle::ඞ::core::marker::PhantomData<
+        fn(::named_generics_bundle::ඞ::ඞ<()>) -> ඞDyn,
+    >
+    where
+        <ඞDyn as DeviceSetup<()>>::Fuel: ඞDeviceSetupඞfields::Fuel,
+        ඞDyn: DeviceSetup<()>,
+    {
+        type Fuel = ඞDyn::Fuel;
+        type Metrics = ඞDyn::Metrics;
+        #[cfg(feature = "turbo")]
+        type Engine = ඞDyn::Engine;
+        type ඞMetricsඞpresent = ඞDyn::ඞMetricsඞpresent;
+    }
+};
+#[doc(hidden)]
+pub use ::named_generics_bundle::ඞ::Present as ඞDeviceSetupඞPresent;
+#[doc(hidden)]
+pub use ::named_generics_bundle::ඞ::Absent as ඞDeviceSetupඞAbsent;
+#[doc(hidden)]
+pub use ::named_generics_bundle::ඞ::NotPresent as ඞDeviceSetupඞNotPresent;
+#[doc(hidden)]
+pub use ::named_generics_bundle::ඞ::bundle_keys as ඞDeviceSetupඞbundle_keys;
+#[cfg(all())]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupඞmacroඞcfg_0 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupඞmacroඞcfg_1! { [$($acc)* $($present)*] $($rest)* }
+    };
+}
+#[cfg(not(all()))]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupඞmacroඞcfg_0 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupඞmacroඞcfg_1! { [$($acc)* $($absent)*] $($rest)* }
+    };
+}
+#[cfg(all(feature = "turbo"))]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupඞmacroඞcfg_1 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupඞmacroඞcfg_2! { [$($acc)* $($present)*] $($rest)* }
+    };
+}
+#[cfg(not(all(feature = "turbo")))]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupඞmacroඞcfg_1 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupඞmacroඞcfg_2! { [$($acc)* $($absent)*] $($rest)* }
+    };
+}
+#[cfg(all())]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupඞmacroඞcfg_2 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupඞmacroඞcfg_3! { [$($acc)* $($present)*] $($rest)* }
+    };
+}
+#[cfg(not(all()))]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupඞmacroඞcfg_2 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupඞmacroඞcfg_3! { [$($acc)* $($absent)*] $($rest)* }
+    };
+}
+#[cfg(all())]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupඞmacroඞcfg_3 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupඞmacroඞcfg_4! { [$($acc)* $($present)*] $($rest)* }
+    };
+}
+#[cfg(not(all()))]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupඞmacroඞcfg_3 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupඞmacroඞcfg_4! { [$($acc)* $($absent)*] $($rest)* }
+    };
+}
+#[cfg(all())]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupඞmacroඞcfg_4 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupඞmacroඞcfg_5! { [$($acc)* $($present)*] $($rest)* }
+    };
+}
+#[cfg(not(all()))]
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupඞmacroඞcfg_4 {
+    ([$($acc:tt)*] [$($present:tt)*] [$($absent:tt)*] $($rest:tt)*) => {
+        __ඞDeviceSetupඞmacroඞcfg_5! { [$($acc)* $($absent)*] $($rest)* }
+    };
+}
+#[doc(hidden)]
+macro_rules! __ඞDeviceSetupඞmacroඞcfg_5 {
+    ([$($acc:tt)*]) => {
+        #[doc = " Instantiate a [`DeviceSetup`](trait@DeviceSetup) bundle."] #[doc = r""]
+        #[doc = " ```rust ,ignore"] #[doc = " DeviceSetup!["] #[doc =
+        "     Fuel = /* impl Clone */,"] #[doc = "     Metrics = /* … */, // optional"]
+        #[doc = "     Engine = /* … */,"] #[doc = " ]"] #[doc = " ```"] #[doc = r""]
+        #[doc = " | Field | Bounds | Docs |"] #[doc = " |-------|--------|------|"] #[doc
+        = " | [`Fuel`](DeviceSetup::Fuel) | `Clone` |  |"] #[doc =
+        " | [`Metrics`](DeviceSetup::Metrics) (optional) |  |  |"] #[doc =
+        " | [`Engine`](DeviceSetup::Engine) |  |  |"] #[doc = r""] #[doc =
+        " The resulting type also implements `Debug + Copy + Ord + Hash + Default + Send + Sync + Unpin`, so as to be"]
+        #[doc = r" dumb-stdlib-`#[derive()]`-friendly."] #[macro_export] #[doc(hidden)]
+        macro_rules! __proper_macro_rules_scopingඞnamed_generics_bundleඞDeviceSetup {
+        $($acc)* }
+    };
+}
+__ඞDeviceSetupඞmacroඞcfg_0! {
+    [] [(@ ඞmunch $base : tt[$($extra : tt)*] $slot0 : tt $slot1 : tt $slot2 : tt
+    $slot3 : tt) => ($crate::setup::DeviceSetup! { @ ඞfinish $base [$($extra)*] $slot0
+    $slot1 $slot2 $slot3 }); (@ ඞmunch[] $extra : tt $slot0 : tt $slot1 : tt $slot2 :
+    tt $slot3 : tt.. $Base : ty $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @
+    ඞmunch[$Base] $extra $slot0 $slot1 $slot2 $slot3 $($($rest)*)? }); (@
+    ඞmunch[$($prev_base : tt)+] $extra : tt $slot0 : tt $slot1 : tt $slot2 : tt $slot3
+    : tt.. $($rest : tt)*) => (::core::compile_error! {
+    "`DeviceSetup![…]`: at most one `..Base` is allowed" });] [(@ ඞmunch $base :
+    tt[$($extra : tt)*] $slot0 : tt $slot1 : tt $slot2 : tt $slot3 : tt) =>
+    ($crate::setup::DeviceSetup! { @ ඞfinish $base [$($extra)*] $slot0 $slot1 $slot2
+    $slot3 }); (@ ඞmunch[] $extra : tt $slot0 : tt $slot1 : tt $slot2 : tt $slot3 : tt
+    .. $Base : ty $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @
+    ඞmunch[$Base] $extra $slot0 $slot1 $slot2 $slot3 $($($rest)*)? }); (@
+    ඞmunch[$($prev_base : tt)+] $extra : tt $slot0 : tt $slot1 : tt $slot2 : tt $slot3
+    : tt.. $($rest : tt)*) => (::core::compile_error! {
+    "`DeviceSetup![…]`: at most one `..Base` is allowed" });] [] [(@ ඞmunch $base :
+    tt $extra : tt $slot0 : tt $slot1 : tt $slot2 : tt $slot3 : tt Engine[$key : tt =
+    $($T : tt)+] $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @ ඞmunch $base
+    $extra $slot0 $slot1 $slot2 $slot3 $($($rest)*)? }); (@ ඞfinish $base : tt[$($acc :
+    tt)*] $({ $($set : tt)* })* [Engine] $($slots : tt)*) => ($crate::setup::DeviceSetup!
+    { @ ඞfinish $base [$($acc)* $($($set)*)*] $($slots)* });] [(@ ඞmunch $base : tt
+    $extra : tt[$unset : ident] $slot1 : tt $slot2 : tt $slot3 : tt Fuel[$key : tt = $($T
+    : tt)+] $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @ ඞmunch $base
+    $extra { $key = < () as $crate::setup::ඞDeviceSetupඞfields::ඞFuel < $($T)+ >>
+    ::ItSelf, } $slot1 $slot2 $slot3 $($($rest)*)? }); (@ ඞmunch $base : tt $extra : tt
+    { $($set : tt)* } $slot1 : tt $slot2 : tt $slot3 : tt Fuel[$key : tt = $($T : tt)+]
+    $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @ ඞmunch $base $extra {
+    $($set)* $key = < () as $crate::setup::ඞDeviceSetupඞfields::ඞFuel < $($T)+ >>
+    ::ItSelf, } $slot1 $slot2 $slot3 $($($rest)*)? }); (@ ඞmunch $base : tt $extra : tt
+    $slot0 : tt[$unset : ident] $slot2 : tt $slot3 : tt Metrics[$key : tt = $($T : tt)+]
+    $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @ ඞmunch $base $extra
+    $slot0 { $key = $($T)+, } $slot2 { ඞMetricsඞpresent =
+    $crate::setup::ඞDeviceSetupඞPresent, } $($($rest)*)? }); (@ ඞmunch $base : tt
+    $extra : tt $slot0 : tt { $($set : tt)* } $slot2 : tt $slot3 : tt Metrics[$key : tt =
+    $($T : tt)+] $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @ ඞmunch $base
+    $extra $slot0 { $($set)* $key = $($T)+, } $slot2 { ඞMetricsඞpresent =
+    $crate::setup::ඞDeviceSetupඞPresent, } $($($rest)*)? }); (@ ඞmunch $base : tt
+    $extra : tt $slot0 : tt $slot1 : tt $slot2 : tt $slot3 : tt ඞMetricsඞpresent[$key
+    : tt = $($T : tt)+] $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @
+    ඞmunch $base $extra $slot0 $slot1 $slot2 { ඞMetricsඞpresent = $($T)+, }
+    $($($rest)*)? }); (@ ඞmunch $base : tt $extra : tt $slot0 : tt $slot1 : tt[$unset :
+    ident] $slot3 : tt Engine[$key : tt = $($T : tt)+] $(, $($rest : tt)*)?) =>
+    ($crate::setup::DeviceSetup! { @ ඞmunch $base $extra $slot0 $slot1 { $key = $($T)+,
+    } $slot3 $($($rest)*)? }); (@ ඞmunch $base : tt $extra : tt $slot0 : tt $slot1 : tt
+    { $($set : tt)* } $slot3 : tt Engine[$key : tt = $($T : tt)+] $(, $($rest : tt)*)?)
+    => ($crate::setup::DeviceSetup! { @ ඞmunch $base $extra $slot0 $slot1 { $($set)*
+    $key = $($T)+, } $slot3 $($($rest)*)? }); (@ ඞmunch $base : tt[$($extra : tt)*]
+    $slot0 : tt $slot1 : tt $slot2 : tt $slot3 : tt $Key : ident[$key : tt = $($T : tt)+]
+    $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @ ඞmunch $base [$($extra)*
+    $key = $($T)+,] $slot0 $slot1 $slot2 $slot3 $($($rest)*)? });] [(@ ඞmunch $base :
+    tt $extra : tt[$unset : ident] $slot1 : tt $slot2 : tt $slot3 : tt Fuel[$key : tt =
+    $($T : tt)+] $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @ ඞmunch $base
+    $extra { $key = < () as $crate::setup::ඞDeviceSetupඞfields::ඞFuel < $($T)+ >>
+    ::ItSelf, } $slot1 $slot2 $slot3 $($($rest)*)? }); (@ ඞmunch $base : tt $extra : tt
+    { $($set : tt)* } $slot1 : tt $slot2 : tt $slot3 : tt Fuel[$key : tt = $($T : tt)+]
+    $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @ ඞmunch $base $extra {
+    $($set)* $key = < () as $crate::setup::ඞDeviceSetupඞfields::ඞFuel < $($T)+ >>
+    ::ItSelf, } $slot1 $slot2 $slot3 $($($rest)*)? }); (@ ඞmunch $base : tt $extra : tt
+    $slot0 : tt[$unset : ident] $slot2 : tt $slot3 : tt Metrics[$key : tt = $($T : tt)+]
+    $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @ ඞmunch $base $extra
+    $slot0 { $key = $($T)+, } $slot2 { ඞMetricsඞpresent =
+    $crate::setup::ඞDeviceSetupඞPresent, } $($($rest)*)? }); (@ ඞmunch $base : tt
+    $extra : tt $slot0 : tt { $($set : tt)* } $slot2 : tt $slot3 : tt Metrics[$key : tt =
+    $($T : tt)+] $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @ ඞmunch $base
+    $extra $slot0 { $($set)* $key = $($T)+, } $slot2 { ඞMetricsඞpresent =
+    $crate::setup::ඞDeviceSetupඞPresent, } $($($rest)*)? }); (@ ඞmunch $base : tt
+    $extra : tt $slot0 : tt $slot1 : tt $slot2 : tt $slot3 : tt ඞMetricsඞpresent[$key
+    : tt = $($T : tt)+] $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @
+    ඞmunch $base $extra $slot0 $slot1 $slot2 { ඞMetricsඞpresent = $($T)+, }
+    $($($rest)*)? }); (@ ඞmunch $base : tt $extra : tt $slot0 : tt $slot1 : tt[$unset :
+    ident] $slot3 : tt Engine[$key : tt = $($T : tt)+] $(, $($rest : tt)*)?) =>
+    ($crate::setup::DeviceSetup! { @ ඞmunch $base $extra $slot0 $slot1 { $key = $($T)+,
+    } $slot3 $($($rest)*)? }); (@ ඞmunch $base : tt $extra : tt $slot0 : tt $slot1 : tt
+    { $($set : tt)* } $slot3 : tt Engine[$key : tt = $($T : tt)+] $(, $($rest : tt)*)?)
+    => ($crate::setup::DeviceSetup! { @ ඞmunch $base $extra $slot0 $slot1 { $($set)*
+    $key = $($T)+, } $slot3 $($($rest)*)? }); (@ ඞmunch $base : tt[$($extra : tt)*]
+    $slot0 : tt $slot1 : tt $slot2 : tt $slot3 : tt $Key : ident[$key : tt = $($T : tt)+]
+    $(, $($rest : tt)*)?) => ($crate::setup::DeviceSetup! { @ ඞmunch $base [$($extra)*
+    $key = $($T)+,] $slot0 $slot1 $slot2 $slot3 $($($rest)*)? });] [(@ ඞmunch $base :
+    tt $extra : tt $slot0 : tt $slot1 : tt $slot2 : tt $slot3 : tt $($rest : tt)+) =>
+    (::core::marker::PhantomData:: < fn (()) -> dyn $crate::setup::DeviceSetup < (),
+    $($rest)+ >>); (@ ඞfinish $base : tt[$($acc : tt)*] $({ $($set : tt)* })*) =>
+    (::core::marker::PhantomData:: < fn (()) -> dyn $crate::setup::DeviceSetup < (),
+    $($acc)* $($($set)*)* >>);] [(@ ඞmunch $base : tt $extra : tt $slot0 : tt $slot1 :
+    tt $slot2 : tt $slot3 : tt $($rest : tt)+) => (::core::marker::PhantomData:: < fn
+    (()) -> dyn $crate::setup::DeviceSetup < (), $($rest)+ >>); (@ ඞfinish $base :
+    tt[$($acc : tt)*] $({ $($set : tt)* })*) => (::core::marker::PhantomData:: < fn (())
+    -> dyn $crate::setup::DeviceSetup < (), $($acc)* $($($set)*)* >>);] [(@ ඞfinish[]
+    [$($acc : tt)*] $({ $($set : tt)* })* [Metrics] $($slots : tt)*) =>
+    ($crate::setup::DeviceSetup! { @ ඞfinish[] [$($acc)* $($($set)*)* Metrics =
+    $crate::setup::ඞDeviceSetupඞAbsent,] $($slots)* }); (@ ඞfinish[] [$($acc :
+    tt)*] $({ $($set : tt)* })* [ඞMetricsඞpresent] $($slots : tt)*) =>
+    ($crate::setup::DeviceSetup! { @ ඞfinish[] [$($acc)* $($($set)*)*
+    ඞMetricsඞpresent = $crate::setup::ඞDeviceSetupඞNotPresent,] $($slots)* }); (@
+    ඞfinish[$($Base : ty)?] [$($acc : tt)*] $({ $($set : tt)* })* [$Field : ident]
+    $($slots : tt)*) => ($crate::setup::DeviceSetup! { @ ඞfinish[$($Base)?] [$($acc)*
+    $($($set)*)* $($Field = < $Base as $crate::setup::DeviceSetup > :: $Field,)?]
+    $($slots)* }); ($(#[$attr : meta])* $pub : vis preset $Preset : ident =
+    $($named_generics : tt)*) => ($(#[$attr])* $pub type $Preset =
+    $crate::setup::DeviceSetup![$($named_generics)*];); (@ ඞwith_fields[$($callback :
+    tt)*] $($args : tt)*) => ($($callback)* ! { $($args)* [$crate::setup::DeviceSetup]
+    [Fuel Metrics #[cfg(feature = "turbo")] Engine] [Metrics] }); (@ ඞimpl_bundle
+    $($args : tt)*) => (::named_generics_bundle::ඞ::impl_bundle_with_fields! {
+    [::named_generics_bundle] $($args)* [$crate::setup::DeviceSetup] [Fuel Metrics
+    #[cfg(feature = "turbo")] Engine] [Metrics] }); ($($named_generics : tt)*) =>
+    ($crate::setup::ඞDeviceSetupඞbundle_keys! { [$crate::setup::DeviceSetup] [@
+    ඞmunch[] [] [Fuel] [Metrics] [Engine] [ඞMetricsඞpresent]] $($named_generics)*
+    });] [(@ ඞfinish[] [$($acc : tt)*] $({ $($set : tt)* })* [Metrics] $($slots : tt)*)
+    => ($crate::setup::DeviceSetup! { @ ඞfinish[] [$($acc)* $($($set)*)* Metrics =
+    $crate::setup::ඞDeviceSetupඞAbsent,] $($slots)* }); (@ ඞfinish[] [$($acc :
+    tt)*] $({ $($set : tt)* })* [ඞMetricsඞpresent] $($slots : tt)*) =>
+    ($crate::setup::DeviceSetup! { @ ඞfinish[] [$($acc)* $($($set)*)*
+    ඞMetricsඞpresent = $crate::setup::ඞDeviceSetupඞNotPresent,] $($slots)* }); (@
+    ඞfinish[$($Base : ty)?] [$($acc : tt)*] $({ $($set : tt)* })* [$Field : ident]
+    $($slots : tt)*) => ($crate::setup::DeviceSetup! { @ ඞfinish[$($Base)?] [$($acc)*
+    $($($set)*)* $($Field = < $Base as $crate::setup::DeviceSetup > :: $Field,)?]
+    $($slots)* }); ($(#[$attr : meta])* $pub : vis preset $Preset : ident =
+    $($named_generics : tt)*) => ($(#[$attr])* $pub type $Preset =
+    $crate::setup::DeviceSetup![$($named_generics)*];); (@ ඞwith_fields[$($callback :
+    tt)*] $($args : tt)*) => ($($callback)* ! { $($args)* [$crate::setup::DeviceSetup]
+    [Fuel Metrics #[cfg(feature = "turbo")] Engine] [Metrics] }); (@ ඞimpl_bundle
+    $($args : tt)*) => (::named_generics_bundle::ඞ::impl_bundle_with_fields! {
+    [::named_generics_bundle] $($args)* [$crate::setup::DeviceSetup] [Fuel Metrics
+    #[cfg(feature = "turbo")] Engine] [Metrics] }); ($($named_generics : tt)*) =>
+    ($crate::setup::ඞDeviceSetupඞbundle_keys! { [$crate::setup::DeviceSetup] [@
+    ඞmunch[] [] [Fuel] [Metrics] [Engine] [ඞMetricsඞpresent]] $($named_generics)*
+    });]
+}
+#[doc(inline)]
+pub use __proper_macro_rules_scopingඞnamed_generics_bundleඞDeviceSetup as DeviceSetup;
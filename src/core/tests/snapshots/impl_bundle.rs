@@ -0,0 +1,15 @@
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+    ::core::cmp::PartialOrd,
+    ::core::cmp::Ord,
+    ::core::hash::Hash,
+    ::core::default::Default,
+)]
+struct Prod;
+DeviceSetup! {
+    @ ඞimpl_bundle[DeviceSetup] [Prod] [Fuel = Uranium]
+}
@@ -0,0 +1,7 @@
+impl DeviceSetup for Prod {
+    type Fuel = Uranium;
+    #[cfg(any())]
+    type Metrics = ::nmb::ඞ::Absent;
+    #[cfg(any())]
+    type ඞMetricsඞpresent = ::nmb::ඞ::NotPresent;
+}
@@ -0,0 +1,11 @@
+fn check<P: crate::DeviceSetup>() {}
+#[::core::prelude::v1::test]
+#[allow(non_snake_case)]
+fn check__Fuel_Uranium() {
+    check::<DeviceSetup![Fuel = Uranium,]>()
+}
+#[::core::prelude::v1::test]
+#[allow(non_snake_case)]
+fn check__Fuel_Vec_u8() {
+    check::<DeviceSetup![Fuel = Vec < u8 >,]>()
+}
@@ -0,0 +1,92 @@
+//! Checks that `path_to_named_generics_bundle_crate` does refer to `::named_generics_bundle`.
+//!
+//! The `ඞ::is_named_generics_bundle` marker is looked up through a glob import, which shadows
+//! a local fallback whose bound errors with a proper message.
+//!
+//! The items of the expansion refer to the crate through a hidden `ඞTraitඞkrate` module, which
+//! merely re-exports `#krate::ඞ`: a wrong path thus results in that one import failing too, with
+//! every other path going through it not being reported (rather than a cascade of errors).
+//! The `macro_rules!` bodies, being resolved at the call site, keep on using `#krate` directly.
+//!
+//! The items are furthermore handed to the `ඞ::expand!` passthrough (through that very import),
+//! so that none get to be emitted for a wrong path: a trait path failing to resolve would
+//! otherwise prevent the marker error from being reported.
+//!
+//! Relaxed bounds are spelled `?::core::marker::Sized` rather than going through it, since a
+//! `?Bound` resolving to that failed import would nonetheless be reported.
+
+use super::*;
+
+/// `(how the module of the trait refers to the crate, the definition thereof)`, the latter to
+/// be emitted through [`wrap()`].
+pub(crate)
+fn validate(
+    TraitName @ _: &Ident,
+    krate: &TokenStream2,
+    krate_path: &Option<Path>,
+) -> (TokenStream2, Option<TokenStream2>)
+{
+    let Some(krate_path) = krate_path else {
+        return (krate.clone(), None);
+    };
+    let ඞTraitඞkrate @ _ = format_ident!("ඞ{TraitName}ඞkrate");
+    let span = krate_path.segments.last().unwrap().ident.span();
+    let message = format!(
+        "`{}` does not refer to `named_generics_bundle`",
+        pretty_tokens(krate_path),
+    );
+    let label = "expected a path to the `named_generics_bundle` crate (or to a re-export of it)";
+    let marker = quote_spanned!(span=>
+        ඞ::is_named_generics_bundle::<()>
+    );
+    let nested_krate = nested_krate(krate);
+    let ඞ @ _ = Ident::new("ඞ", span);
+    (
+        ඞTraitඞkrate.to_token_stream(),
+        Some(quote!(
+            const _: () = {
+                #[allow(nonstandard_style)]
+                mod ඞ {
+                    #[diagnostic::on_unimplemented(message = #message, label = #label)]
+                    pub trait NamedGenericsBundle {}
+
+                    pub fn is_named_generics_bundle<T : NamedGenericsBundle>() {}
+                }
+                {
+                    #[allow(unused_imports)]
+                    use #krate::*;
+                    let _ = #marker;
+                }
+            };
+
+            #[doc(hidden)]
+            #[allow(nonstandard_style)]
+            mod #ඞTraitඞkrate {
+                #[allow(unused_imports)]
+                use super::*;
+
+                pub use #nested_krate::#ඞ;
+            }
+        )),
+    )
+}
+
+/// The `expansion`, along with the definitions of [`validate()`] (if any).
+pub(crate)
+fn wrap(
+    krate: &TokenStream2,
+    definitions: &Option<TokenStream2>,
+    expansion: TokenStream2,
+) -> TokenStream2
+{
+    let Some(definitions) = definitions else {
+        return expansion;
+    };
+    quote!(
+        #definitions
+
+        #krate::ඞ::expand! {
+            #expansion
+        }
+    )
+}
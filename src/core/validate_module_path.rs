@@ -5,7 +5,11 @@ use super::*;
 pub(crate)
 fn validate(krate: &TokenStream2, module_path: &Option<Path>) -> Option<TokenStream2> {
     module_path.as_ref().map(|module_path| {
-        let module_path = module_path.to_token_stream().into_iter().collect::<Vec<_>>();
+        let mut module_path = module_path.to_token_stream().into_iter().collect::<Vec<_>>();
+        // A `$crate` path (from a `macro_rules!` expansion) is compared as `crate`.
+        if matches!(&module_path[0], TT::Ident(it) if it == "$crate") {
+            module_path[0] = Ident::new("crate", module_path[0].span()).into();
+        }
         let start_span = module_path.first().unwrap().span();
         let end_span = module_path.last().unwrap().span();
         let panic = quote_spanned!(start_span=>
@@ -0,0 +1,27 @@
+//! `#[bundle(optional)]` fields.
+
+/// The type of an optional (`#[bundle(optional)]`) field whose key has been omitted.
+///
+/// It ought to satisfy the bounds of the field, much like a "null object" would: for a local
+/// trait, this is just a matter of `impl Recorder for Absent { … }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Absent;
+
+/// The (hidden) presence flag of an optional field.
+pub trait Presence {
+    const PRESENT: bool;
+}
+
+/// The key of the optional field has been given.
+pub struct Present;
+
+impl Presence for Present {
+    const PRESENT: bool = true;
+}
+
+/// The key of the optional field has been omitted.
+pub struct NotPresent;
+
+impl Presence for NotPresent {
+    const PRESENT: bool = false;
+}
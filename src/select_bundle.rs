@@ -0,0 +1,210 @@
+//! Runtime selection among (monomorphized) bundle instantiations.
+
+/// Picks, at runtime, a bundle instantiation out of some `&str` choices, and runs the given
+/// body with it.
+///
+/// The body is expanded (and thus monomorphized) once per possible combination, with `P`
+/// being the selected bundle; the whole expression evaluates to a
+/// <code>[Result]\<_, [UnknownChoice]\></code>.
+///
+/// ```rust
+/// # pub struct Uranium; pub struct TestSubject; pub struct Turbo; pub struct Mock;
+/// use ::named_generics_bundle::{named_generics_bundle, select_bundle, UnknownChoice};
+///
+/// #[named_generics_bundle]
+/// trait DeviceSetup {
+///     type Fuel;
+///     type Engine;
+/// }
+///
+/// fn run<P : DeviceSetup>() -> &'static str {
+///     ::core::any::type_name::<P::Fuel>()
+/// }
+///
+/// // Per-field alternatives (the cartesian product of which is covered).
+/// let fuel = String::from("t");
+/// let outcome = select_bundle!(DeviceSetup {
+///     Fuel in { "u" | "uranium" => Uranium, "t" => TestSubject } = &fuel,
+///     Engine in { "turbo" => Turbo, "mock" => Mock } = "mock",
+/// } => |P| run::<P>());
+/// assert!(outcome.unwrap().ends_with("TestSubject"));
+///
+/// // Named presets.
+/// DeviceSetup![preset Production = Fuel = Uranium, Engine = Turbo];
+/// DeviceSetup![preset Testing = Fuel = TestSubject, Engine = Mock];
+///
+/// let outcome = select_bundle!(DeviceSetup in {
+///     "prod" => Production,
+///     "test" => Testing,
+/// } = "staging" => |P| run::<P>());
+/// assert_eq!(outcome, Err(UnknownChoice {
+///     key: "DeviceSetup",
+///     found: "staging",
+///     expected: &["prod", "test"],
+/// }));
+/// ```
+///
+/// Every choice expression is evaluated exactly once, upfront, in order.
+///
+/// Since the body is inlined, `return`, `?` and `.await` in it apply to the enclosing function.
+#[macro_export]
+macro_rules! select_bundle {
+    (
+        $($Bundle:ident)::+ {
+            $(
+                $Field:ident in { $( $($key:literal)|+ => $T:ty ),* $(,)? } = $choice:expr
+            ),+ $(,)?
+        } => |$P:ident| $body:expr $(,)?
+    ) => (
+        $crate::ඞselect_bundle! {
+            @bind
+            [$($Bundle)::+]
+            []
+            {$(
+                $Field in { $( $($key)|+ => $T ),* } = $choice,
+            )+}
+            [$P]
+            [$body]
+        }
+    );
+
+    (
+        $($Bundle:ident)::+ in {
+            $( $($key:literal)|+ => $Preset:ty ),* $(,)?
+        } = $choice:expr => |$P:ident| $body:expr $(,)?
+    ) => (
+        match $crate::ඞ::str_choice($choice) {
+            $(
+                $($key)|+ => $crate::ඞ::core::result::Result::Ok({
+                    #[allow(unused)]
+                    type $P = $Preset;
+                    $body
+                }),
+            )*
+            found => $crate::ඞ::core::result::Result::Err($crate::UnknownChoice {
+                key: $crate::ඞ::last_path_segment($crate::ඞ::core::stringify!($($Bundle)::+)),
+                found,
+                expected: &[$($($key),+),*],
+            }),
+        }
+    );
+}
+
+#[doc(hidden)] /** Not part of the public API */ #[macro_export]
+macro_rules! ඞselect_bundle {
+    // Evaluate every choice once, upfront, binding it to a (hygienically distinct) `choice`, so
+    // that the nested `match`es below only ever repeat that identifier.
+    (
+        @bind $Bundle:tt [$($bound:tt)*] {
+            $Field:ident in $choices:tt = $choice:expr,
+            $($rest:tt)*
+        } $P:tt $body:tt
+    ) => (
+        match $crate::ඞ::str_choice($choice) {
+            choice => $crate::ඞselect_bundle! {
+                @bind $Bundle [$($bound)* [$Field $choices choice]] { $($rest)* } $P $body
+            },
+        }
+    );
+
+    (
+        @bind $Bundle:tt $bound:tt {} $P:tt $body:tt
+    ) => (
+        $crate::ඞselect_bundle! {
+            $Bundle [] $bound $P $body
+        }
+    );
+
+    (
+        @pick $Bundle:tt [$($picked:tt)*] [$($pick:tt)*] $($rest:tt)*
+    ) => (
+        $crate::ඞselect_bundle! {
+            $Bundle [$($picked)* $($pick)*,] $($rest)*
+        }
+    );
+
+    // Every field has been picked.
+    (
+        [$($Bundle:tt)*] [$($picked:tt)*] [] [$P:ident] [$body:expr]
+    ) => (
+        $crate::ඞ::core::result::Result::Ok({
+            #[allow(unused)]
+            type $P = $($Bundle)*![$($picked)*];
+            $body
+        })
+    );
+
+    (
+        @field $Bundle:tt $picked:tt
+        [$Field:ident { $( $($key:literal)|+ => $T:ty ),* } $choice:ident]
+        $rest:tt $P:tt $body:tt
+    ) => (
+        match $choice {
+            $(
+                $($key)|+ => $crate::ඞselect_bundle! {
+                    @pick $Bundle $picked [$Field = $T] $rest $P $body
+                },
+            )*
+            found => $crate::ඞ::core::result::Result::Err($crate::UnknownChoice {
+                key: $crate::ඞ::core::stringify!($Field),
+                found,
+                expected: &[$($($key),+),*],
+            }),
+        }
+    );
+
+    (
+        $Bundle:tt $picked:tt [$field:tt $($rest:tt)*] $P:tt $body:tt
+    ) => (
+        $crate::ඞselect_bundle! {
+            @field $Bundle $picked $field [$($rest)*] $P $body
+        }
+    );
+}
+
+/// The error yielded by [`select_bundle!`] when given an unknown choice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnknownChoice<'found> {
+    /// The field (or bundle trait name, when choosing among presets) being chosen.
+    pub key: &'static str,
+    /// The offending choice.
+    pub found: &'found str,
+    /// The known choices.
+    pub expected: &'static [&'static str],
+}
+
+impl ::core::fmt::Display for UnknownChoice<'_> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self { key, found, expected } = self;
+        write!(f, "unknown `{key}` choice: `{found}` (expected one of: ")?;
+        for (i, choice) in expected.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{choice}`")?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl ::core::error::Error for UnknownChoice<'_> {}
+
+/// Deref-coerces the choice to a `&str`, borrowing the original `String` (if any) rather than
+/// some temporary.
+#[doc(hidden)] pub
+const
+fn str_choice(choice: &str) -> &str {
+    choice
+}
+
+/// `"m :: DeviceSetup"` to `"DeviceSetup"`.
+#[doc(hidden)] pub
+const
+fn last_path_segment(path: &'static str) -> &'static str {
+    let bytes = path.as_bytes();
+    let mut i = bytes.len();
+    while i > 0 && bytes[i - 1] != b':' && bytes[i - 1] != b' ' {
+        i -= 1;
+    }
+    path.split_at(i).1
+}
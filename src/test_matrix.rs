@@ -0,0 +1,65 @@
+//! One `#[test]` per combination of field candidates.
+
+/// Generates one `#[test]` per combination of the given field candidates, running the given
+/// generic function with the matching bundle.
+///
+/// ```rust
+/// # pub struct Uranium; pub struct TestSubject; pub struct FluxCapacitor;
+/// # pub trait Burns {} impl Burns for Uranium {} impl Burns for TestSubject {}
+/// use ::named_generics_bundle::{bundle_test_matrix, named_generics_bundle};
+///
+/// #[named_generics_bundle]
+/// trait DeviceSetup {
+///     type Fuel : Burns;
+///     type Engine;
+/// }
+///
+/// bundle_test_matrix!(DeviceSetup {
+///     Fuel: [Uranium, TestSubject],
+///     Engine: [FluxCapacitor],
+/// } => fn check<P>() {
+///     // `P : DeviceSetup` is implied.
+///     fn burns<F : Burns>() {}
+///     burns::<P::Fuel>();
+/// });
+/// # fn main() {}
+/// ```
+///
+/// expands to:
+///
+/// ```rust
+/// # pub struct Uranium; pub struct TestSubject; pub struct FluxCapacitor;
+/// # pub trait Burns {} impl Burns for Uranium {} impl Burns for TestSubject {}
+/// # #[::named_generics_bundle::named_generics_bundle]
+/// # trait DeviceSetup { type Fuel : Burns; type Engine; }
+/// fn check<P : DeviceSetup>() {
+///     // …
+/// }
+///
+/// #[test]
+/// #[allow(non_snake_case)]
+/// fn check__Fuel_Uranium__Engine_FluxCapacitor() {
+///     check::<DeviceSetup![Fuel = Uranium, Engine = FluxCapacitor,]>()
+/// }
+///
+/// #[test]
+/// #[allow(non_snake_case)]
+/// fn check__Fuel_TestSubject__Engine_FluxCapacitor() {
+///     check::<DeviceSetup![Fuel = TestSubject, Engine = FluxCapacitor,]>()
+/// }
+/// # fn main() {}
+/// ```
+///
+///   - Every field is to be given candidates (but for `#[cfg]`-dependent ones), lest the macro
+///     error, naming the missing fields.
+///
+///   - The function may return some `Result`, in which case so do the tests.
+#[macro_export]
+macro_rules! bundle_test_matrix {(
+    $($Bundle:ident)::+ { $($matrix:tt)* } => $($test_fn:tt)*
+) => (
+    $($Bundle)::+! {
+        @ඞwith_fields [$crate::ඞ::bundle_test_matrix]
+        [$($Bundle)::+ { $($matrix)* } => $($test_fn)*]
+    }
+)}
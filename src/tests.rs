@@ -20,4 +20,4 @@ pub mod doctest_module_path {
 
 pub use doctest_module_path::Demo;
 
-// No unit tests at the moment (not that great with proc-macros).
+// Expansion tests (on `proc-macro2` types) live in `src/core/tests`.
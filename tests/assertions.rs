@@ -0,0 +1,45 @@
+use ::named_generics_bundle::{assert_bundle_field, assert_same_bundle, impl_bundle};
+
+pub struct Uranium;
+pub struct Turbo;
+pub struct Mock;
+
+pub mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::m,
+    )]
+    pub trait DeviceSetup {
+        type Fuel;
+
+        #[cfg(test)]
+        type Engine;
+
+        #[cfg(not(test))]
+        type Metrics;
+    }
+}
+
+m::DeviceSetup![preset Production = Fuel = Uranium, Engine = Turbo];
+pub type Testing = m::DeviceSetup![..Production, Fuel = Mock];
+
+#[impl_bundle(m::DeviceSetup![Fuel = Uranium, Engine = Turbo])]
+pub struct Prod;
+
+assert_same_bundle!(Testing, m::DeviceSetup![Engine = Turbo, Fuel = Mock]);
+assert_same_bundle!(Production, Production);
+// Field-by-field, `cfg`-ed out fields included.
+assert_same_bundle!(Prod, Production : m::DeviceSetup);
+assert_same_bundle!(Testing, m::DeviceSetup![Engine = Turbo, Fuel = Mock] : m::DeviceSetup,);
+assert_same_bundle!(Testing, Production : m::DeviceSetup, except Fuel);
+assert_same_bundle!(Prod, m::DeviceSetup![..Prod, Engine = Mock] : m::DeviceSetup, except Engine,);
+
+assert_bundle_field!(Testing: m::DeviceSetup, Fuel == Mock);
+assert_bundle_field!(Prod: m::DeviceSetup, Fuel == Uranium, Engine == Turbo,);
+
+#[test]
+fn main() {
+    // Also usable within function bodies.
+    assert_same_bundle!(Prod, Production : m::DeviceSetup);
+    assert_bundle_field!(Production: m::DeviceSetup, Engine == Turbo);
+    assert_same_bundle!(Production, Testing : m::DeviceSetup, except Fuel);
+}
@@ -0,0 +1,54 @@
+use ::named_generics_bundle::assert_same_bundle;
+
+pub trait Burns {
+    fn energy(&self) -> u32;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Uranium(u32);
+impl Burns for Uranium {
+    fn energy(&self) -> u32 { self.0 }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct FluxCapacitor;
+
+pub struct Beam;
+
+pub mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::m,
+        builder = DeviceSetupBuilder,
+    )]
+    pub trait DeviceSetup {
+        type Fuel : crate::Burns;
+        type FluxEngine;
+
+        #[bundle(type_only)]
+        type Output;
+
+        #[cfg(not(test))]
+        type Metrics;
+    }
+}
+
+fn run<P : m::DeviceSetup>(_: P, fuel: P::Fuel) -> u32 {
+    fuel.energy()
+}
+
+#[test]
+fn main() {
+    let (setup, fuel, engine) =
+        m::DeviceSetupBuilder::new()
+            .output::<Beam>()
+            .flux_engine(FluxCapacitor)
+            .fuel(Uranium(42))
+            .build()
+    ;
+    assert_eq!(engine, FluxCapacitor);
+    assert_eq!(run(setup, fuel), 42);
+
+    type Built = m::DeviceSetup![Fuel = Uranium, FluxEngine = FluxCapacitor, Output = Beam];
+    let _: Built = setup;
+    assert_same_bundle!(Built, m::DeviceSetup![Output = Beam, Fuel = Uranium, FluxEngine = FluxCapacitor]);
+}
@@ -0,0 +1,33 @@
+macro_rules! bundles {( $($Name:ident : $Bound:path),* $(,)? ) => (
+    ::named_generics_bundle::named_generics_bundles! {
+        #![named_generics_bundle(implied_bounds(Debug, Clone, Copy))]
+      $(
+        /// Outer.
+        pub trait $Name : 'static {
+            type Item : $Bound;
+        }
+      )*
+
+        #[named_generics_bundle(implied_bounds(Default))]
+        trait WithDefault {}
+    }
+)}
+
+bundles! {
+    A : Iterator,
+    B : ::core::fmt::Display,
+}
+
+fn demo<P : A, Q : B, R : WithDefault>() -> impl Copy {
+    let _ = R::default();
+    (::core::marker::PhantomData::<P>, ::core::marker::PhantomData::<Q>)
+}
+
+#[test]
+fn main() {
+    _ = demo::<
+        A![Item = ::core::iter::Empty<()>],
+        B![Item = String],
+        WithDefault![],
+    >();
+}
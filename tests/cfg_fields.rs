@@ -0,0 +1,32 @@
+pub trait Recorder {}
+impl Recorder for () {}
+
+pub mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::m,
+    )]
+    pub trait DeviceSetup {
+        type Fuel : Clone;
+
+        #[cfg(not(test))]
+        #[bundle(alias = Stats)]
+        type Metrics : crate::Recorder;
+
+        #[cfg(test)]
+        #[cfg_attr(test, doc = "Enabled.")]
+        #[bundle(alias = Tele)]
+        type Telemetry : crate::Recorder;
+    }
+}
+
+fn telemetry<P : m::DeviceSetup>(telemetry: P::Telemetry) -> P::Telemetry {
+    telemetry
+}
+
+#[test]
+fn main() {
+    // Keys of cfg-ed out fields are tolerated.
+    telemetry::<m::DeviceSetup![Fuel = (), Metrics = u8, Telemetry = ()]>(());
+    telemetry::<m::DeviceSetup![Stats = String, Fuel = (), Tele = ()]>(());
+    telemetry::<m::DeviceSetup![Fuel = (), Telemetry = ()]>(());
+}
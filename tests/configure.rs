@@ -0,0 +1,66 @@
+mod reexports {
+    pub use ::named_generics_bundle as nmb;
+}
+
+::named_generics_bundle::configure! {
+    macro_rules! my_bundle {
+        path_to_named_generics_bundle_crate = crate::reexports::nmb,
+        implied_bounds(Debug, Clone, Copy, PartialEq, Eq),
+        export = never,
+    }
+}
+
+my_bundle! {
+    /// Outer.
+    pub trait MyBundle : 'static {
+        type A : Iterator;
+    }
+}
+
+my_bundle! {
+    #[named_generics_bundle(implied_bounds(Default))]
+    trait OtherBundle {
+        type A;
+    }
+}
+
+#[::named_generics_bundle::apply(my_bundle!)]
+/// Outer.
+pub trait AttrBundle : 'static {
+    type A : Iterator;
+}
+
+::named_generics_bundle::configure! {
+    macro_rules! implied_default_bundle {
+        path_to_named_generics_bundle_crate = crate::reexports::nmb,
+        implied_bounds(Default),
+    }
+}
+
+mod nested {
+    #[::named_generics_bundle::apply(implied_default_bundle!)]
+    #[named_generics_bundle(path_to_this_very_module = crate::nested)]
+    pub trait NestedBundle {
+        type A;
+    }
+}
+
+fn attr_demo<P : AttrBundle>() -> impl Copy + Eq + ::core::fmt::Debug {
+    ::core::marker::PhantomData::<P>
+}
+
+fn demo<P : MyBundle>() -> impl Copy + Eq + ::core::fmt::Debug {
+    ::core::marker::PhantomData::<P>
+}
+
+fn default<P : ::core::default::Default>() -> P {
+    P::default()
+}
+
+#[test]
+fn main() {
+    _ = demo::<MyBundle![A = ::core::iter::Empty<()>]>();
+    _ = default::<OtherBundle![A = ()]>();
+    _ = attr_demo::<AttrBundle![A = ::core::iter::Empty<()>]>();
+    _ = default::<nested::NestedBundle![A = ()]>();
+}
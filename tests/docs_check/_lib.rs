@@ -0,0 +1,51 @@
+//! Bundles showing up in a public signature, so that `cargo doc --features docs-rs` gets to
+//! render (and thus type-check) them under `cfg(doc)`.
+
+#[::named_generics_bundle::named_generics_bundle(path_to_this_very_module = crate)]
+pub trait DeviceSetup {
+    type Fuel : Copy;
+
+    #[bundle(optional)]
+    type Metrics;
+}
+
+pub type Prod = DeviceSetup![Fuel = u8];
+
+pub struct Dev<S : DeviceSetup> {
+    pub fuel: S::Fuel,
+}
+
+pub fn make() -> Dev<Prod> {
+    Dev { fuel: 0 }
+}
+
+/// No `path_to_this_very_module`: the (`#[macro_export]`ed) macro is to be reached through the
+/// crate root, and the trait, through its own path (see `tests/downstream`).
+#[::named_generics_bundle::named_generics_bundle]
+pub trait RootSetup {
+    type Fuel;
+    type Exhaust;
+}
+
+/// `RootSetup` is rendered as is, even under `better-docs`.
+pub type Root = RootSetup![Fuel = u8, Exhaust = ()];
+
+pub fn root() -> ::core::marker::PhantomData<Root> {
+    ::core::marker::PhantomData
+}
+
+pub mod nested {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::nested,
+    )]
+    pub trait NestedSetup : 'static {
+        type Item : PartialEq + ::core::fmt::Debug;
+        type Extra : ?Sized;
+    }
+
+    pub type Unsized = NestedSetup![Item = i32, Extra = str];
+
+    pub fn nested() -> ::core::marker::PhantomData<Unsized> {
+        ::core::marker::PhantomData
+    }
+}
@@ -0,0 +1,29 @@
+//! Uses the bundles of `named-generics-bundle-docs_check` without depending on
+//! `named-generics-bundle` itself: the eponymous macros are not to refer to the latter.
+
+use ::named_generics_bundle_docs_check::{self as upstream, RootSetup};
+
+pub type Root = upstream::RootSetup![Exhaust = (), Fuel = u8];
+
+pub type Preset = upstream::RootSetup![..Root, Fuel = u16];
+
+pub type Setup = upstream::DeviceSetup![Fuel = u8];
+
+pub type Metered = upstream::DeviceSetup![Metrics = (), ..Setup];
+
+pub type Nested = upstream::nested::NestedSetup![Item = (), Extra = [u8]];
+
+pub fn fuel<S : RootSetup<Fuel = u16>>() -> ::core::marker::PhantomData<S> {
+    ::core::marker::PhantomData
+}
+
+pub fn preset() -> ::core::marker::PhantomData<Preset> {
+    fuel()
+}
+
+#[allow(clippy::assertions_on_constants)]
+const _: () = {
+    use upstream::DeviceSetupExt;
+    assert!(Metered::HAS_METRICS);
+    assert!(!Setup::HAS_METRICS);
+};
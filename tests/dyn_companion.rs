@@ -0,0 +1,68 @@
+pub trait Burns {
+    fn energy(&self) -> u32;
+}
+
+impl<F : ?Sized + Burns> Burns for Box<F> {
+    fn energy(&self) -> u32 {
+        (**self).energy()
+    }
+}
+
+pub struct Uranium;
+impl Burns for Uranium {
+    fn energy(&self) -> u32 { 42 }
+}
+
+pub struct TestSubject;
+impl Burns for TestSubject {
+    fn energy(&self) -> u32 { 0 }
+}
+
+pub mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::m,
+        dyn_companion = DynDeviceSetup,
+        sealed,
+    )]
+    pub trait DeviceSetup {
+        type Fuel : crate::Burns + Send + 'static;
+        type Payload;
+
+        #[cfg(not(test))]
+        type Metrics : Clone;
+    }
+}
+
+struct Device<S : m::DeviceSetup> {
+    fuel: S::Fuel,
+    payload: S::Payload,
+}
+
+impl<S : m::DeviceSetup> Device<S> {
+    fn energy(&self) -> u32 {
+        self.fuel.energy()
+    }
+}
+
+fn device(fuel: &str) -> Device<m::DynDeviceSetup> {
+    Device {
+        fuel: match fuel {
+            "u" => Box::new(Uranium),
+            _ => Box::new(TestSubject),
+        },
+        payload: Box::new(27_u8),
+    }
+}
+
+#[test]
+fn main() {
+    let static_device = Device::<m::DeviceSetup![Fuel = Uranium, Payload = ()]> {
+        fuel: Uranium,
+        payload: (),
+    };
+    assert_eq!(static_device.energy(), 42);
+
+    assert_eq!(device("u").energy(), 42);
+    assert_eq!(device("t").energy(), 0);
+    assert_eq!(device("u").payload.downcast_ref::<u8>(), Some(&27));
+}
@@ -0,0 +1,24 @@
+pub mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::m,
+    )]
+    pub trait DeviceSetup {
+        #[bundle(alias = Gas, alias = Petrol)]
+        type Fuel : Clone;
+
+        #[deprecated]
+        type Motor;
+    }
+}
+
+fn fuel<P : m::DeviceSetup>(fuel: P::Fuel) -> P::Fuel {
+    fuel.clone()
+}
+
+#[test]
+#[allow(deprecated)]
+fn main() {
+    let _: u8 = fuel::<m::DeviceSetup![Gas = u8, Motor = ()]>(42);
+    let _: u8 = fuel::<m::DeviceSetup![Motor = (), Petrol = u8,]>(42);
+    let _: u8 = fuel::<m::DeviceSetup![Fuel = u8, Motor = ()]>(42);
+}
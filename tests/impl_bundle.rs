@@ -0,0 +1,35 @@
+pub struct Uranium;
+pub struct Turbo;
+
+pub mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::m,
+    )]
+    pub trait DeviceSetup : 'static {
+        type Fuel;
+        type Engine;
+
+        #[cfg(not(test))]
+        type Metrics;
+    }
+}
+
+#[::named_generics_bundle::impl_bundle(m::DeviceSetup![
+    Fuel = Uranium,
+    Engine = Turbo,
+    #[cfg(not(test))]
+    Metrics = (),
+])]
+/// Production setup.
+pub struct Prod;
+
+fn engine<P : m::DeviceSetup>() -> &'static str {
+    ::core::any::type_name::<P::Engine>()
+}
+
+#[test]
+fn main() {
+    assert_eq!(engine::<Prod>(), engine::<m::DeviceSetup![Engine = Turbo, ..Prod]>());
+    fn derives<T : Copy + Ord + ::core::hash::Hash + Default + ::core::fmt::Debug>() {}
+    derives::<Prod>();
+}
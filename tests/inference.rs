@@ -0,0 +1,41 @@
+pub trait Burns {
+    fn energy(&self) -> u32;
+}
+
+pub struct Uranium;
+
+impl Burns for Uranium {
+    fn energy(&self) -> u32 {
+        42
+    }
+}
+
+pub struct FluxCapacitor;
+
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    #[bundle(alias = Gas)]
+    type Fuel : Burns;
+    type Engine;
+}
+
+DeviceSetup![preset Production = Fuel = Uranium, Engine = FluxCapacitor];
+
+fn run<P : DeviceSetup>(fuel: P::Fuel) -> (u32, &'static str) {
+    (fuel.energy(), ::core::any::type_name::<P::Engine>())
+}
+
+#[test]
+fn main() {
+    let (energy, engine) = run::<DeviceSetup![Fuel = _, Engine = FluxCapacitor]>(Uranium);
+    assert_eq!(energy, 42);
+    assert!(engine.ends_with("FluxCapacitor"));
+
+    // Through an alias.
+    let (energy, _) = run::<DeviceSetup![Gas = _, Engine = ()]>(Uranium);
+    assert_eq!(energy, 42);
+
+    // Overriding a preset field.
+    let (_, engine) = run::<DeviceSetup![..Production, Fuel = _]>(Uranium);
+    assert!(engine.ends_with("FluxCapacitor"));
+}
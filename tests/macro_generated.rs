@@ -0,0 +1,30 @@
+pub struct Uranium;
+
+/// Exported, so it can only refer to its own crate through `$crate`.
+#[macro_export]
+macro_rules! generate_bundle {
+    ($TraitName:ident) => {
+        #[::named_generics_bundle::named_generics_bundle(
+            path_to_this_very_module = $crate::generated,
+        )]
+        pub trait $TraitName {
+            type Fuel;
+        }
+    };
+}
+
+pub mod generated {
+    generate_bundle!(DeviceSetup);
+}
+
+mod elsewhere {
+    pub type Production = crate::generated::DeviceSetup![Fuel = crate::Uranium];
+}
+
+#[test]
+fn main() {
+    fn fuel<P : generated::DeviceSetup>() -> &'static str {
+        ::core::any::type_name::<P::Fuel>()
+    }
+    assert!(fuel::<elsewhere::Production>().ends_with("Uranium"));
+}
@@ -0,0 +1,70 @@
+//! Bundles with many fields, well within the default `recursion_limit`.
+
+pub struct Uranium;
+
+pub mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::m,
+    )]
+    pub trait DeviceSetup {
+        type F0; type F1; type F2; type F3; type F4; type F5; type F6; type F7; type F8; type F9;
+        type F10; type F11; type F12; type F13; type F14; type F15; type F16; type F17; type F18;
+        type F19; type F20; type F21; type F22; type F23; type F24; type F25; type F26; type F27;
+        type F28; type F29; type F30; type F31; type F32; type F33; type F34; type F35; type F36;
+        type F37; type F38; type F39; type F40; type F41; type F42; type F43; type F44; type F45;
+        type F46; type F47; type F48; type F49; type F50; type F51; type F52; type F53; type F54;
+        type F55; type F56; type F57; type F58; type F59; type F60; type F61; type F62; type F63;
+        type F64; type F65; type F66; type F67; type F68; type F69; type F70; type F71; type F72;
+        type F73; type F74; type F75; type F76; type F77; type F78; type F79; type F80; type F81;
+        type F82; type F83; type F84; type F85; type F86; type F87; type F88; type F89; type F90;
+        type F91; type F92; type F93; type F94; type F95; type F96; type F97; type F98;
+
+        #[bundle(optional)]
+        type Metrics;
+    }
+}
+
+m::DeviceSetup![preset Production =
+    F0 = (), F1 = (), F2 = (), F3 = (), F4 = (), F5 = (), F6 = (), F7 = (), F8 = (), F9 = (),
+    F10 = (), F11 = (), F12 = (), F13 = (), F14 = (), F15 = (), F16 = (), F17 = (), F18 = (),
+    F19 = (), F20 = (), F21 = (), F22 = (), F23 = (), F24 = (), F25 = (), F26 = (), F27 = (),
+    F28 = (), F29 = (), F30 = (), F31 = (), F32 = (), F33 = (), F34 = (), F35 = (), F36 = (),
+    F37 = (), F38 = (), F39 = (), F40 = (), F41 = (), F42 = (), F43 = (), F44 = (), F45 = (),
+    F46 = (), F47 = (), F48 = (), F49 = (), F50 = (), F51 = (), F52 = (), F53 = (), F54 = (),
+    F55 = (), F56 = (), F57 = (), F58 = (), F59 = (), F60 = (), F61 = (), F62 = (), F63 = (),
+    F64 = (), F65 = (), F66 = (), F67 = (), F68 = (), F69 = (), F70 = (), F71 = (), F72 = (),
+    F73 = (), F74 = (), F75 = (), F76 = (), F77 = (), F78 = (), F79 = (), F80 = (), F81 = (),
+    F82 = (), F83 = (), F84 = (), F85 = (), F86 = (), F87 = (), F88 = (), F89 = (), F90 = (),
+    F91 = (), F92 = (), F93 = (), F94 = (), F95 = (), F96 = (), F97 = (), F98 = (),
+    Metrics = Uranium,
+];
+
+type Bare = m::DeviceSetup![
+    F0 = (), F1 = (), F2 = (), F3 = (), F4 = (), F5 = (), F6 = (), F7 = (), F8 = (), F9 = (),
+    F10 = (), F11 = (), F12 = (), F13 = (), F14 = (), F15 = (), F16 = (), F17 = (), F18 = (),
+    F19 = (), F20 = (), F21 = (), F22 = (), F23 = (), F24 = (), F25 = (), F26 = (), F27 = (),
+    F28 = (), F29 = (), F30 = (), F31 = (), F32 = (), F33 = (), F34 = (), F35 = (), F36 = (),
+    F37 = (), F38 = (), F39 = (), F40 = (), F41 = (), F42 = (), F43 = (), F44 = (), F45 = (),
+    F46 = (), F47 = (), F48 = (), F49 = (), F50 = (), F51 = (), F52 = (), F53 = (), F54 = (),
+    F55 = (), F56 = (), F57 = (), F58 = (), F59 = (), F60 = (), F61 = (), F62 = (), F63 = (),
+    F64 = (), F65 = (), F66 = (), F67 = (), F68 = (), F69 = (), F70 = (), F71 = (), F72 = (),
+    F73 = (), F74 = (), F75 = (), F76 = (), F77 = (), F78 = (), F79 = (), F80 = (), F81 = (),
+    F82 = (), F83 = (), F84 = (), F85 = (), F86 = (), F87 = (), F88 = (), F89 = (), F90 = (),
+    F91 = (), F92 = (), F93 = (), F94 = (), F95 = (), F96 = (), F97 = (), F98 = (),
+];
+
+type Overridden = m::DeviceSetup![F42 = Uranium, ..Production];
+
+fn metrics<P : m::DeviceSetup<F42 = F42>, F42>() -> bool {
+    P::HAS_METRICS
+}
+
+#[test]
+fn main() {
+    assert!(metrics::<Production, ()>());
+    assert!(metrics::<Bare, ()>().not());
+    assert!(metrics::<Overridden, Uranium>());
+}
+
+use ::core::ops::Not as _;
+use m::DeviceSetupExt as _;
@@ -0,0 +1,118 @@
+use ::named_generics_bundle::{Absent, assert_bundle_field, impl_bundle};
+
+pub trait Recorder {
+    fn record(&self, event: &str) -> usize;
+}
+
+impl Recorder for Absent {
+    fn record(&self, _: &str) -> usize {
+        0
+    }
+}
+
+impl<R : ?Sized + Recorder> Recorder for Box<R> {
+    fn record(&self, event: &str) -> usize {
+        (**self).record(event)
+    }
+}
+
+pub struct Counter;
+
+impl Recorder for Counter {
+    fn record(&self, event: &str) -> usize {
+        event.len()
+    }
+}
+
+pub struct Uranium;
+
+pub mod states {
+    pub struct Absent;
+
+    impl crate::Recorder for Absent {
+        fn record(&self, _: &str) -> usize {
+            0
+        }
+    }
+}
+
+pub mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::m,
+        builder = DeviceSetupBuilder,
+        dyn_companion = DynDeviceSetup,
+    )]
+    pub trait DeviceSetup {
+        type Fuel;
+
+        #[bundle(optional, alias = Stats)]
+        type Metrics : crate::Recorder;
+
+        #[cfg(not(test))]
+        #[bundle(optional)]
+        type Tracing;
+    }
+}
+
+use m::DeviceSetupExt;
+
+m::DeviceSetup![preset Production = Fuel = Uranium, Metrics = Counter];
+
+#[impl_bundle(m::DeviceSetup![Fuel = Uranium])]
+pub struct Bare;
+
+#[impl_bundle(m::DeviceSetup![Fuel = Uranium, Metrics = Absent])]
+pub struct ExplicitlyBare;
+
+assert_bundle_field!(m::DeviceSetup![Fuel = ()]: m::DeviceSetup, Metrics == Absent);
+assert_bundle_field!(Bare: m::DeviceSetup, Metrics == Absent);
+
+fn record<P : m::DeviceSetup>(metrics: &P::Metrics) -> Option<usize> {
+    P::metrics(metrics).map(|it| it.record("boot"))
+}
+
+// Usable at compile time.
+#[allow(clippy::assertions_on_constants)]
+const _: () = {
+    assert!(!<m::DeviceSetup![Fuel = ()]>::HAS_METRICS);
+    assert!(<m::DeviceSetup![Fuel = (), Stats = Counter]>::HAS_METRICS);
+    assert!(Production::HAS_METRICS);
+    assert!(!Bare::HAS_METRICS);
+
+    // Any explicit type counts as present, whatever its name.
+    assert!(<m::DeviceSetup![Fuel = (), Metrics = Absent]>::HAS_METRICS);
+    assert!(<m::DeviceSetup![Stats = ::named_generics_bundle::Absent, Fuel = ()]>::HAS_METRICS);
+    assert!(<m::DeviceSetup![Metrics = Absent, ..Production]>::HAS_METRICS);
+    assert!(<m::DeviceSetup![Fuel = (), Metrics = states::Absent]>::HAS_METRICS);
+    assert!(ExplicitlyBare::HAS_METRICS);
+    assert!(m::DynDeviceSetup::HAS_METRICS);
+
+    // Picked from the `..Base`, and overridable.
+    assert!(<m::DeviceSetup![Fuel = (), ..Production]>::HAS_METRICS);
+    assert!(<m::DeviceSetup![..Production, Metrics = Counter]>::HAS_METRICS);
+};
+
+#[test]
+fn main() {
+    assert_eq!(record::<m::DeviceSetup![Fuel = ()]>(&Absent), None);
+    assert_eq!(record::<m::DeviceSetup![Fuel = (), Metrics = Absent]>(&Absent), Some(0));
+    assert_eq!(record::<Production>(&Counter), Some(4));
+    assert_eq!(record::<m::DynDeviceSetup>(&(Box::new(Counter) as Box<dyn Recorder>)), Some(4));
+
+    // Optional fields may be left unset.
+    let (bundle, Uranium, Absent) = m::DeviceSetupBuilder::new().fuel(Uranium).build();
+    let _: m::DeviceSetup![Fuel = Uranium] = bundle;
+    assert!(bundle_has_metrics(bundle).not());
+    let (bundle, Uranium, Counter) = m::DeviceSetupBuilder::new()
+        .metrics(Counter)
+        .fuel(Uranium)
+        .build()
+    ;
+    assert!(bundle_has_metrics(bundle));
+}
+
+fn bundle_has_metrics<P : m::DeviceSetup>(_: P) -> bool {
+    P::HAS_METRICS
+}
+
+use ::core::ops::Not as _;
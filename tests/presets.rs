@@ -0,0 +1,38 @@
+#[derive(Default)]
+pub struct Uranium;
+#[derive(Default)]
+pub struct Mock;
+pub struct Turbo;
+
+pub mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::m,
+    )]
+    pub trait DeviceSetup {
+        #[bundle(alias = Gas)]
+        type Fuel : Default;
+        type Engine;
+
+        #[cfg(not(test))]
+        type Metrics;
+    }
+}
+
+m::DeviceSetup![pub preset Production = Fuel = Uranium, Engine = Turbo];
+
+m::DeviceSetup! {
+    /// Overrides the `Fuel`.
+    preset Testing = ..Production, Gas = Mock, Metrics = ()
+}
+
+fn fuel<P : m::DeviceSetup>() -> P::Fuel {
+    P::Fuel::default()
+}
+
+#[test]
+fn main() {
+    let _: Uranium = fuel::<Production>();
+    let _: Mock = fuel::<Testing>();
+    let _: Uranium = fuel::<m::DeviceSetup![Fuel = Uranium, ..Testing]>();
+    let _: u8 = fuel::<m::DeviceSetup![..m::DeviceSetup![Fuel = u8, Engine = ()]]>();
+}
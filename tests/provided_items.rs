@@ -0,0 +1,58 @@
+pub trait Burns {
+    fn energy(&self) -> u32;
+}
+
+pub struct Uranium;
+
+impl Burns for Uranium {
+    fn energy(&self) -> u32 {
+        42
+    }
+}
+
+pub struct FluxCapacitor;
+
+#[::named_generics_bundle::named_generics_bundle(
+    dyn_companion = DynDeviceSetup,
+)]
+trait DeviceSetup {
+    type Fuel : Burns;
+    type Engine;
+
+    /// How many runs to batch together.
+    const MAX_BATCH: usize = 32;
+
+    fn name() -> &'static str
+    where
+        Self : Sized,
+    {
+        ::core::any::type_name::<Self::Engine>()
+    }
+
+    fn total_energy(fuels: &[Self::Fuel]) -> u32 {
+        fuels.iter().take(Self::MAX_BATCH).map(Burns::energy).sum()
+    }
+}
+
+DeviceSetup![preset Production = Fuel = Uranium, Engine = FluxCapacitor];
+
+fn run<P : DeviceSetup>(fuels: &[P::Fuel]) -> (u32, &'static str) {
+    (P::total_energy(fuels), P::name())
+}
+
+#[test]
+fn main() {
+    assert_eq!(Production::MAX_BATCH, 32);
+    assert_eq!(<DeviceSetup![Fuel = Uranium, Engine = ()]>::name(), "()");
+    let (energy, name) = run::<Production>(&[Uranium, Uranium]);
+    assert_eq!(energy, 84);
+    assert!(name.ends_with("FluxCapacitor"));
+    // The bundle trait itself remains `dyn`-compatible.
+    assert_eq!(DynDeviceSetup::total_energy(&[Box::new(Uranium) as Box<dyn Burns>]), 42);
+}
+
+impl<B : ?Sized + Burns> Burns for Box<B> {
+    fn energy(&self) -> u32 {
+        (**self).energy()
+    }
+}
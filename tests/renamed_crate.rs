@@ -13,3 +13,24 @@ fn _demo<B : _MyBundle>() {
         _MyBundle![A = ::core::iter::Empty<()>]
     >;
 }
+
+/// As with a `renamed = { package = "named-generics-bundle", … }` dependency: an extern prelude
+/// name, with no leading `::`.
+pub mod extern_prelude {
+    #[::renamed::named_generics_bundle(
+        path_to_this_very_module = crate::extern_prelude,
+        path_to_named_generics_bundle_crate = renamed,
+        sealed,
+    )]
+    pub trait SealedSetup {
+        type Fuel : Clone;
+
+        #[bundle(optional)]
+        type Metrics;
+    }
+
+    fn _demo<B : SealedSetup>(_: B::Fuel) {
+        _ = _demo::<SealedSetup![Fuel = ()]>;
+        _ = _demo::<SealedSetup![Fuel = (), Metrics = ()]>;
+    }
+}
@@ -0,0 +1,46 @@
+pub mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::m,
+        sealed,
+    )]
+    pub trait DeviceSetup {
+        type Fuel : Clone;
+    }
+}
+
+fn fuel<P : m::DeviceSetup>(fuel: &P::Fuel) -> P::Fuel {
+    fuel.clone()
+}
+
+#[test]
+fn main() {
+    m::DeviceSetup![preset Base = Fuel = u8];
+    let _: u8 = fuel::<Base>(&42);
+    let _: String = fuel::<m::DeviceSetup![..Base, Fuel = String]>(&String::new());
+}
+
+mod reexports {
+    pub use ::named_generics_bundle as ngb;
+}
+
+pub mod relative {
+    use crate::reexports;
+
+    // A relative crate path, as seen from the (nested) sealing module.
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_named_generics_bundle_crate = reexports::ngb,
+        sealed,
+    )]
+    pub trait SealedSetup {
+        type Fuel : Clone;
+    }
+
+    #[test]
+    fn main() {
+        let _: u8 = super::fuel_relative::<SealedSetup![Fuel = u8]>(&42);
+    }
+}
+
+fn fuel_relative<P : relative::SealedSetup>(fuel: &P::Fuel) -> P::Fuel {
+    fuel.clone()
+}
@@ -0,0 +1,76 @@
+use ::named_generics_bundle::{select_bundle, UnknownChoice};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Uranium;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TestSubject;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Turbo;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Mock;
+
+pub mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::m,
+    )]
+    pub trait DeviceSetup {
+        type Fuel : ::core::fmt::Debug + Default;
+        type Engine : ::core::fmt::Debug + Default;
+    }
+
+    DeviceSetup![pub preset Production = Fuel = crate::Uranium, Engine = crate::Turbo];
+}
+
+fn run<P : m::DeviceSetup>() -> String {
+    format!("{:?} {:?}", P::Fuel::default(), P::Engine::default())
+}
+
+fn select<'s>(fuel: &'s str, engine: &'s str) -> Result<String, UnknownChoice<'s>> {
+    select_bundle!(m::DeviceSetup {
+        Fuel in { "u" | "uranium" => Uranium, "t" => TestSubject } = fuel,
+        Engine in { "turbo" => Turbo, "mock" => Mock, } = engine,
+    } => |P| run::<P>())
+}
+
+#[test]
+fn fields() {
+    assert_eq!(select("u", "turbo").unwrap(), "Uranium Turbo");
+    assert_eq!(select("uranium", "mock").unwrap(), "Uranium Mock");
+    assert_eq!(select("t", "turbo").unwrap(), "TestSubject Turbo");
+    assert_eq!(select("t", "mock").unwrap(), "TestSubject Mock");
+
+    let err = select("x", "mock").unwrap_err();
+    assert_eq!(err, UnknownChoice { key: "Fuel", found: "x", expected: &["u", "uranium", "t"] });
+    assert_eq!(err.to_string(), "unknown `Fuel` choice: `x` (expected one of: `u`, `uranium`, `t`)");
+    assert_eq!(select("u", "x").unwrap_err().key, "Engine");
+}
+
+#[test]
+fn presets() {
+    let choice = String::from("prod");
+    let outcome = select_bundle!(m::DeviceSetup in {
+        "prod" => m::Production,
+        "test" => m::DeviceSetup![..m::Production, Fuel = TestSubject, Engine = Mock],
+    } = &choice => |Setup| run::<Setup>());
+    assert_eq!(outcome.unwrap(), "Uranium Turbo");
+
+    let outcome = select_bundle!(m::DeviceSetup in {
+        "prod" => m::Production,
+    } = "test" => |P| run::<P>());
+    assert_eq!(outcome.unwrap_err().to_string(), "unknown `DeviceSetup` choice: `test` (expected one of: `prod`)");
+}
+
+#[test]
+fn choices_are_evaluated_once() {
+    let evaluations = ::core::cell::Cell::new(0);
+    let choice = |it| {
+        evaluations.set(evaluations.get() + 1);
+        it
+    };
+    let outcome = select_bundle!(m::DeviceSetup {
+        Fuel in { "u" => Uranium, "t" => TestSubject } = choice("t"),
+        Engine in { "turbo" => Turbo, "mock" => Mock } = choice("mock"),
+    } => |P| (run::<P>(), choice("body")));
+    assert_eq!(outcome.unwrap(), ("TestSubject Mock".into(), "body"));
+    assert_eq!(evaluations.get(), 3);
+}
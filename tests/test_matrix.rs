@@ -0,0 +1,54 @@
+use ::named_generics_bundle::bundle_test_matrix;
+
+pub trait Burns { const ENERGY: u32; }
+
+pub struct Uranium;
+impl Burns for Uranium { const ENERGY: u32 = 42; }
+
+pub struct TestSubject;
+impl Burns for TestSubject { const ENERGY: u32 = 0; }
+
+pub struct FluxCapacitor;
+
+pub mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::m,
+    )]
+    pub trait DeviceSetup {
+        type Fuel : crate::Burns;
+        type Engine;
+
+        #[cfg(not(test))]
+        type Metrics;
+    }
+}
+
+bundle_test_matrix!(m::DeviceSetup {
+    Fuel: [Uranium, TestSubject],
+    Engine: [FluxCapacitor, (), Vec<u8>],
+} => fn check<P>() {
+    let _ = P::Fuel::ENERGY;
+});
+
+bundle_test_matrix!(m::DeviceSetup {
+    Engine: [()],
+    Fuel: [Uranium, crate::Uranium],
+} => fn fallible<P>() -> Result<(), String> {
+    match P::Fuel::ENERGY {
+        42 => Ok(()),
+        _ => Err("no energy".into()),
+    }
+});
+
+#[test]
+fn names() {
+    check__Fuel_Uranium__Engine_FluxCapacitor();
+    check__Fuel_Uranium__Engine_unit();
+    check__Fuel_Uranium__Engine_Vec_u8();
+    check__Fuel_TestSubject__Engine_FluxCapacitor();
+    check__Fuel_TestSubject__Engine_unit();
+    check__Fuel_TestSubject__Engine_Vec_u8();
+
+    fallible__Engine_unit__Fuel_Uranium().unwrap();
+    fallible__Engine_unit__Fuel_crate_Uranium().unwrap();
+}
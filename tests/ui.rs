@@ -0,0 +1,7 @@
+//! `.stderr` snapshots of the diagnostics. Regenerate with `TRYBUILD=overwrite`.
+#![cfg(feature = "ui-tests")]
+
+#[test]
+fn ui() {
+    ::trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,18 @@
+pub struct Uranium;
+pub struct Turbo;
+
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel;
+    type Engine;
+}
+
+DeviceSetup![preset Production = Fuel = Uranium, Engine = Turbo];
+
+::named_generics_bundle::assert_bundle_field!(
+    Production: DeviceSetup,
+    Fuel == Uranium,
+    Engine == Uranium,
+);
+
+fn main() {}
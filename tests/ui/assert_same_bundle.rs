@@ -0,0 +1,23 @@
+pub struct Uranium;
+pub struct Turbo;
+pub struct Mock;
+
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel;
+    type Engine;
+}
+
+DeviceSetup![preset Production = Fuel = Uranium, Engine = Turbo];
+
+::named_generics_bundle::assert_same_bundle!(
+    DeviceSetup![..Production, Fuel = Mock],
+    Production,
+);
+
+::named_generics_bundle::assert_same_bundle!(
+    DeviceSetup![Fuel = Mock, Engine = Mock],
+    Production : DeviceSetup,
+);
+
+fn main() {}
@@ -0,0 +1,17 @@
+pub struct Uranium;
+pub struct Turbo;
+pub struct Mock;
+
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel;
+    type Engine;
+}
+
+DeviceSetup![preset Production = Fuel = Uranium, Engine = Turbo];
+type Testing = DeviceSetup![..Production, Fuel = Mock];
+
+// `Fuel` differs as well.
+::named_generics_bundle::assert_same_bundle!(Testing, Production : DeviceSetup, except Engine);
+
+fn main() {}
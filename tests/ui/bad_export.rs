@@ -0,0 +1,6 @@
+#[::named_generics_bundle::named_generics_bundle(export = sometimes)]
+trait DeviceSetup {
+    type Fuel;
+}
+
+fn main() {}
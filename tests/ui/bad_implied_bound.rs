@@ -0,0 +1,6 @@
+#[::named_generics_bundle::named_generics_bundle(implied_bounds(Debug, Display))]
+trait DeviceSetup {
+    type Fuel;
+}
+
+fn main() {}
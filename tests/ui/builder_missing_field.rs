@@ -0,0 +1,11 @@
+pub struct Uranium;
+
+#[::named_generics_bundle::named_generics_bundle(builder = DeviceSetupBuilder)]
+trait DeviceSetup {
+    type Fuel;
+    type Engine;
+}
+
+fn main() {
+    let _ = DeviceSetupBuilder::new().fuel(Uranium).build();
+}
@@ -0,0 +1,7 @@
+::named_generics_bundle::configure! {
+    macro_rules! my_bundle {
+        path_to_this_very_module = crate::m,
+    }
+}
+
+fn main() {}
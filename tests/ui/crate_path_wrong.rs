@@ -0,0 +1,12 @@
+mod reexports {
+    pub use ::core as nmb;
+}
+
+#[::named_generics_bundle::named_generics_bundle(
+    path_to_named_generics_bundle_crate = crate::reexports::nmb,
+)]
+trait DeviceSetup {
+    type Fuel;
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![deny(deprecated)]
+
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel;
+
+    #[deprecated(note = "no longer used")]
+    type Motor;
+}
+
+type Example = DeviceSetup![
+    Fuel = (),
+    Motor = (),
+];
+
+fn main() {}
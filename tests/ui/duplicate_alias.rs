@@ -0,0 +1,8 @@
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    #[bundle(alias = Engine)]
+    type Fuel;
+    type Engine;
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+pub trait Burns {}
+
+#[::named_generics_bundle::named_generics_bundle(dyn_companion = DynDeviceSetup)]
+trait DeviceSetup {
+    type Fuel : Burns + Clone;
+    type Engine : Burns + ::core::fmt::Display;
+    type Payload : Send;
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[::named_generics_bundle::named_generics_bundle(dyn_companion = DynDeviceSetup)]
+trait DeviceSetup {
+    #[cfg(all())]
+    type Metrics : Default;
+
+    // Cfg-ed out: no error.
+    #[cfg(any())]
+    type Telemetry : Default;
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+pub trait Burns {
+    fn energy(&self) -> u32;
+}
+
+#[::named_generics_bundle::named_generics_bundle(dyn_companion = DynDeviceSetup)]
+trait DeviceSetup {
+    type Fuel : Burns + Send;
+    type Payload;
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel : PartialEq<Self>;
+    type Engine : ?Sized + Fn(&Self, Self::Fuel) -> Self;
+    type Gadget<T>;
+    // Fine.
+    type Payload : Iterator<Item = Self> + From<<Self as DeviceSetup>::Fuel>;
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel;
+    #[cfg(any())]
+    type Disabled<T>;
+    #[cfg(not(any()))]
+    type Enabled : PartialEq<Self>;
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+trait Burns {}
+
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel: Burns;
+}
+
+fn run<P: DeviceSetup>() {}
+
+fn main() {
+    run::<DeviceSetup![Fuel = String]>();
+}
@@ -0,0 +1,16 @@
+mod m {
+    pub trait Burns {}
+
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::m,
+    )]
+    pub trait DeviceSetup {
+        type Fuel: Burns;
+    }
+}
+
+fn run<P: m::DeviceSetup>() {}
+
+fn main() {
+    run::<m::DeviceSetup![Fuel = String]>();
+}
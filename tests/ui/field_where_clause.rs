@@ -0,0 +1,7 @@
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel : Clone where Self : Sized;
+    type Engine where Self::Fuel : Clone;
+}
+
+fn main() {}
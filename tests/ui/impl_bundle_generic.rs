@@ -0,0 +1,9 @@
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel;
+}
+
+#[::named_generics_bundle::impl_bundle(DeviceSetup![Fuel = T])]
+struct Mine<T>(T);
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel;
+    type Engine;
+}
+
+fn main() {
+    let _: DeviceSetup![Fuel = ()];
+}
@@ -0,0 +1,18 @@
+macro_rules! generate_bundle {
+    () => {
+        #[::named_generics_bundle::named_generics_bundle(
+            path_to_this_very_module = $crate::elsewhere,
+        )]
+        pub trait DeviceSetup {
+            type Fuel;
+        }
+    };
+}
+
+mod generated {
+    generate_bundle!();
+}
+
+mod elsewhere {}
+
+fn main() {}
@@ -0,0 +1,10 @@
+mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = my_crate::m,
+    )]
+    pub trait DeviceSetup {
+        type Fuel;
+    }
+}
+
+fn main() {}
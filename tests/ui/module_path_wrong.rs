@@ -0,0 +1,12 @@
+mod m {
+    #[::named_generics_bundle::named_generics_bundle(
+        path_to_this_very_module = crate::n,
+    )]
+    pub trait DeviceSetup {
+        type Fuel;
+    }
+}
+
+mod n {}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel;
+    fn burn();
+}
+
+fn main() {}
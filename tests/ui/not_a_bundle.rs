@@ -0,0 +1,11 @@
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel;
+    type Engine;
+}
+
+fn run<P: DeviceSetup>() {}
+
+fn main() {
+    run::<String>();
+}
@@ -0,0 +1,11 @@
+mod upstream {
+    #[::named_generics_bundle::named_generics_bundle(sealed)]
+    pub trait DeviceSetup {
+        type Fuel;
+    }
+}
+
+#[::named_generics_bundle::impl_bundle(upstream::DeviceSetup![Fuel = ()])]
+struct Mine;
+
+fn main() {}
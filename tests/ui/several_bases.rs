@@ -0,0 +1,10 @@
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel;
+}
+
+type A = DeviceSetup![Fuel = ()];
+
+fn main() {
+    let _: DeviceSetup![..A, ..A];
+}
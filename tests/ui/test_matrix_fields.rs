@@ -0,0 +1,15 @@
+pub struct Uranium;
+
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel;
+    type Engine;
+    type Exhaust;
+}
+
+::named_generics_bundle::bundle_test_matrix!(DeviceSetup {
+    Fuel: [Uranium],
+    Fule: [Uranium],
+} => fn check<P>() {});
+
+fn main() {}
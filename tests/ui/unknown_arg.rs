@@ -0,0 +1,6 @@
+#[::named_generics_bundle::named_generics_bundle(seal)]
+trait DeviceSetup {
+    type Fuel;
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[::named_generics_bundle::named_generics_bundle]
+trait DeviceSetup {
+    type Fuel;
+}
+
+fn main() {
+    let _: DeviceSetup![Fuel = (), Oil = ()];
+}